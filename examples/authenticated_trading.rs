@@ -127,7 +127,7 @@ async fn main() -> Result<()> {
     // let order_builder = OrderBuilder::new(
     //     signer.clone(),               // EOA signer (no Box::new!)
     //     Some(SignatureType::PolyProxy),
-    //     Some(proxy_wallet_address),   // Proxy wallet holds funds
+    //     Some(proxy_wallet_address),   // Proxy wallet holds funds (None derives it)
     // );
     //
     // let trading_client = TradingClient::new(
@@ -73,7 +73,7 @@ async fn main() -> Result<()> {
 
     // Test 5: Get events
     println!("\n5. Fetching all events...");
-    match client.get_events().await {
+    match client.get_events(None).await {
         Ok(events) => {
             if let Some(event) = events.first() {
                 println!("   Sample event: {}", event.title);
@@ -96,15 +96,16 @@ async fn main() -> Result<()> {
         ])
         .await?;
 
-    println!("\n✅ Successfully posted {} orders!", results.len());
+    println!("\n✅ Posted {} orders!", results.len());
 
     for (i, result) in results.iter().enumerate() {
         println!("\nOrder {}:", i + 1);
-        println!("  Order ID: {}", result.order_id.as_str());
-        println!("  Status: {}", result.status);
-        println!("  Success: {}", result.success);
-        if !result.error_msg.is_empty() {
-            println!("  Error: {}", result.error_msg);
+        match result {
+            Ok(response) => {
+                println!("  Order ID: {}", response.order_id.as_str());
+                println!("  Status: {}", response.status);
+            }
+            Err(e) => println!("  Rejected: {}", e),
         }
     }
     Ok(())
@@ -11,7 +11,7 @@ async fn main() -> Result<()> {
 
     // Get all positions for a user
     println!("Fetching positions for user: {}...\n", user_address);
-    match client.get_positions(user_address).await {
+    match client.get_positions(user_address, None).await {
         Ok(positions) => {
             println!("Found {} positions:", positions.len());
 
@@ -27,7 +27,9 @@ async fn main() -> Result<()> {
                 println!("  Percent P&L: {}%", position.percent_pnl);
                 println!("  Realized P&L: ${}", position.realized_pnl);
                 println!("  Redeemable: {}", position.redeemable);
-                println!("  End Date: {}", position.end_date);
+                if let Some(end_date) = &position.end_date {
+                    println!("  End Date: {}", end_date);
+                }
 
                 // Only show first 5 positions to keep output manageable
                 if i >= 4 {
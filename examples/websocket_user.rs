@@ -39,6 +39,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_delay: Duration::from_secs(30),
         multiplier: 2.0,
         max_attempts: None, // Unlimited reconnection attempts
+        jitter: 0.2,
     };
 
     // Create a reconnecting stream that will automatically reconnect on disconnection
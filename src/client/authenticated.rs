@@ -1,7 +1,9 @@
 use crate::error::{Error, Result};
-use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
+use crate::http::{build_l1_headers, build_l2_headers, Headers, HttpClient};
 use crate::signing::EthSigner;
-use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
+use crate::types::{
+    ApiCreds, ApiKeysResponse, AssetType, BalanceAllowance, BalanceAllowanceParams,
+};
 use alloy_primitives::{Address, U256};
 
 /// Client for authenticated operations
@@ -51,6 +53,52 @@ impl AuthenticatedClient {
         }
     }
 
+    /// Use a custom HTTP client, see [`ClobClient::with_http_client`](crate::client::ClobClient::with_http_client)
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Correct signed timestamps for the offset of the local clock
+    ///
+    /// See [`TradingClient::sync_clock`](crate::client::TradingClient::sync_clock);
+    /// applies to both L1 and L2 headers. Returns the skew in seconds.
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.http_client.sync_server_time().await
+    }
+
+    /// Clock skew measured by [`sync_clock`](Self::sync_clock), if any
+    pub fn clock_skew(&self) -> Option<i64> {
+        self.http_client.clock_skew()
+    }
+
+    /// L1 headers signed at the server-corrected time
+    fn l1_headers(&self, nonce: Option<U256>) -> Result<Headers> {
+        let timestamp = self.http_client.server_time_secs()?;
+        build_l1_headers(&self.signer, self.chain_id, nonce, timestamp)
+    }
+
+    /// L2 headers signed at the server-corrected time
+    fn l2_headers<T>(
+        &self,
+        api_creds: &ApiCreds,
+        method: &str,
+        req_path: &str,
+        body: Option<&T>,
+    ) -> Result<Headers>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        build_l2_headers(
+            self.signer.address(),
+            api_creds,
+            self.http_client.server_time_secs()?,
+            method,
+            req_path,
+            body,
+        )
+    }
+
     /// Get the API credentials if available
     ///
     /// Returns a reference to the API credentials if they were provided when creating
@@ -132,7 +180,7 @@ impl AuthenticatedClient {
     /// This creates a new API key for the signer's address.
     /// Requires wallet signature.
     pub async fn create_api_key(&self, nonce: Option<U256>) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, nonce)?;
+        let headers = self.l1_headers(nonce)?;
         self.http_client
             .post("/auth/api-key", &serde_json::json!({}), Some(headers))
             .await
@@ -140,7 +188,12 @@ impl AuthenticatedClient {
 
     /// Derive API key from existing credentials (L1 authentication required)
     pub async fn derive_api_key(&self) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, None)?;
+        self.derive_api_key_with_nonce(None).await
+    }
+
+    /// Derive the API key created with a specific nonce (L1 authentication required)
+    pub async fn derive_api_key_with_nonce(&self, nonce: Option<U256>) -> Result<ApiCreds> {
+        let headers = self.l1_headers(nonce)?;
         self.http_client
             .get("/auth/derive-api-key", Some(headers))
             .await
@@ -148,11 +201,22 @@ impl AuthenticatedClient {
 
     /// Create or derive API key with fallback
     ///
-    /// Tries to create a new API key, falls back to derive if creation fails.
+    /// Tries to create a new API key and falls back to deriving the existing
+    /// one if the API rejects the creation (e.g. because a key already exists
+    /// for this nonce). Transport and signing errors are returned as-is.
     pub async fn create_or_derive_api_key(&self) -> Result<ApiCreds> {
-        match self.create_api_key(None).await {
+        self.create_or_derive_api_key_with_nonce(None).await
+    }
+
+    /// Create or derive the API key for a specific nonce
+    pub async fn create_or_derive_api_key_with_nonce(
+        &self,
+        nonce: Option<U256>,
+    ) -> Result<ApiCreds> {
+        match self.create_api_key(nonce).await {
             Ok(creds) => Ok(creds),
-            Err(_) => self.derive_api_key().await,
+            Err(Error::Api { .. }) => self.derive_api_key_with_nonce(nonce).await,
+            Err(e) => Err(e),
         }
     }
 
@@ -163,8 +227,7 @@ impl AuthenticatedClient {
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/auth/api-keys", None)?;
+        let headers = self.l2_headers::<()>(api_creds, "GET", "/auth/api-keys", None)?;
         self.http_client.get("/auth/api-keys", Some(headers)).await
     }
 
@@ -175,8 +238,7 @@ impl AuthenticatedClient {
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "DELETE", "/auth/api-key", None)?;
+        let headers = self.l2_headers::<()>(api_creds, "DELETE", "/auth/api-key", None)?;
         self.http_client
             .delete("/auth/api-key", Some(headers))
             .await
@@ -185,19 +247,30 @@ impl AuthenticatedClient {
     /// Get balance and allowance information (L2 authentication required)
     ///
     /// # Arguments
-    /// * `params` - Query parameters for balance/allowance
+    /// * `params` - Query parameters for balance/allowance, e.g.
+    ///   [`BalanceAllowanceParams::collateral`] or
+    ///   [`BalanceAllowanceParams::conditional`] for one outcome token
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] when querying conditional tokens
+    /// without a token ID.
     pub async fn get_balance_allowance(
         &self,
         params: BalanceAllowanceParams,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<BalanceAllowance> {
         let api_creds = self
             .api_creds
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
+        if params.asset_type == Some(AssetType::Conditional) && params.token_id.is_none() {
+            return Err(Error::InvalidParameter(
+                "token_id is required for conditional balances".to_string(),
+            ));
+        }
 
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/balance-allowance";
-        let headers = create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None)?;
+        let headers = self.l2_headers::<()>(api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
         let query_params = params.to_query_params();
@@ -225,13 +298,7 @@ impl AuthenticatedClient {
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers = create_l2_headers::<_, ()>(
-            &self.signer,
-            api_creds,
-            "GET",
-            "/balance-allowance/update",
-            None,
-        )?;
+        let headers = self.l2_headers::<()>(api_creds, "GET", "/balance-allowance/update", None)?;
         self.http_client
             .get("/balance-allowance/update", Some(headers))
             .await
@@ -244,8 +311,7 @@ impl AuthenticatedClient {
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", "/notifications", None)?;
+        let headers = self.l2_headers::<()>(api_creds, "GET", "/notifications", None)?;
         self.http_client.get("/notifications", Some(headers)).await
     }
 
@@ -257,13 +323,7 @@ impl AuthenticatedClient {
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
 
         let body = serde_json::json!({ "ids": ids });
-        let headers = create_l2_headers(
-            &self.signer,
-            api_creds,
-            "DELETE",
-            "/notifications",
-            Some(&body),
-        )?;
+        let headers = self.l2_headers(api_creds, "DELETE", "/notifications", Some(&body))?;
         self.http_client
             .delete_with_body("/notifications", &body, Some(headers))
             .await
@@ -282,3 +342,84 @@ impl AuthenticatedClient {
         self.funder
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+    use alloy_signer_local::PrivateKeySigner;
+
+    const CREDS: &str = r#"{"apiKey":"key","secret":"c2VjcmV0","passphrase":"pass"}"#;
+
+    fn client(url: &str) -> AuthenticatedClient {
+        AuthenticatedClient::new(url, PrivateKeySigner::random(), 137, None, None)
+    }
+
+    #[tokio::test]
+    async fn test_create_or_derive_falls_back_when_key_exists() {
+        let server = TestServer::start(vec![
+            MockResponse::json(400, r#"{"error":"API key already exists"}"#),
+            MockResponse::json(200, CREDS),
+        ])
+        .await;
+
+        let creds = client(&server.url)
+            .create_or_derive_api_key_with_nonce(Some(U256::from(7)))
+            .await
+            .unwrap();
+        assert_eq!(creds.api_key, "key");
+        assert_eq!(creds.passphrase, "pass");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/auth/api-key");
+        assert_eq!(requests[1].method, "GET");
+        assert_eq!(requests[1].path, "/auth/derive-api-key");
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_allowance() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"balance":"2000000","allowance":"1500000"}"#,
+        )])
+        .await;
+        let client = AuthenticatedClient::new(
+            &server.url,
+            PrivateKeySigner::random(),
+            137,
+            Some(serde_json::from_str(CREDS).unwrap()),
+            None,
+        );
+
+        let balance = client
+            .get_balance_allowance(BalanceAllowanceParams::conditional("123"))
+            .await
+            .unwrap();
+        assert_eq!(balance.balance, rust_decimal::Decimal::from(2));
+        assert_eq!(balance.allowance, rust_decimal::Decimal::new(15, 1));
+        assert_eq!(
+            server.requests()[0].path,
+            "/balance-allowance?asset_type=CONDITIONAL&token_id=123"
+        );
+
+        let missing_token = BalanceAllowanceParams::new().asset_type(AssetType::Conditional);
+        assert!(matches!(
+            client.get_balance_allowance(missing_token).await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_or_derive_returns_created_key() {
+        let server = TestServer::start(vec![MockResponse::json(200, CREDS)]).await;
+
+        let creds = client(&server.url)
+            .create_or_derive_api_key()
+            .await
+            .unwrap();
+        assert_eq!(creds.api_key, "key");
+        assert_eq!(server.requests().len(), 1);
+    }
+}
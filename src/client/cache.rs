@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// In-memory cache of values fetched from the API
+///
+/// Entries expire after `ttl` (or never when it is `None`) and can be
+/// invalidated explicitly.
+#[derive(Debug)]
+pub(crate) struct TtlCache<K, V> {
+    entries: Mutex<HashMap<K, (V, Instant)>>,
+    ttl: Option<Duration>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub(crate) fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    pub(crate) fn set_ttl(&mut self, ttl: Option<Duration>) {
+        self.ttl = ttl;
+    }
+
+    /// Cached value for `key`, unless missing or expired
+    ///
+    /// An expired entry is dropped.
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.lock();
+        let (value, inserted) = entries.get(key)?;
+        if self.is_expired(inserted) {
+            entries.remove(key);
+            return None;
+        }
+        Some(value.clone())
+    }
+
+    /// Cache `value` for `key`, dropping every expired entry
+    pub(crate) fn insert(&self, key: K, value: V) {
+        let mut entries = self.lock();
+        entries.retain(|_, (_, inserted)| !self.is_expired(inserted));
+        entries.insert(key, (value, Instant::now()));
+    }
+
+    pub(crate) fn invalidate(&self, key: &K) {
+        self.lock().remove(key);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn is_expired(&self, inserted: &Instant) -> bool {
+        self.ttl.is_some_and(|ttl| inserted.elapsed() >= ttl)
+    }
+
+    /// The entries, even if another thread panicked while holding the lock
+    fn lock(&self) -> MutexGuard<'_, HashMap<K, (V, Instant)>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_and_invalidation() {
+        let cache = TtlCache::new(Some(Duration::ZERO));
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // Inserting "b" dropped the expired "a", reading "b" drops it too
+        assert_eq!(cache.lock().len(), 1);
+        assert_eq!(cache.get(&"b"), None);
+        assert!(cache.lock().is_empty());
+
+        let cache = TtlCache::new(None);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.get(&"a"), Some(1));
+
+        cache.invalidate(&"a");
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(2));
+        cache.clear();
+        assert_eq!(cache.get(&"b"), None);
+    }
+}
@@ -1,12 +1,19 @@
-use crate::error::Result;
+use super::cache::TtlCache;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
+use crate::orders::{round_size, round_to_tick, DEFAULT_FEE_RATE_BPS, ROUNDING_CONFIG};
 use crate::request::PaginationParams;
 use crate::types::{
-    BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
+    BookParams, ConditionId, CreateOrderOptions, FeeRate, FeeRateResponse, Market, MarketsResponse,
+    MidpointResponse, NegRiskResponse, OrderArgs, OrderBookStats, OrderBookSummary,
+    PriceHistoryResponse, PriceResponse, RewardsParams, RewardsResponse, SimplifiedMarketsResponse,
     SpreadResponse, TickSizeResponse, TokenId,
 };
+use crate::utils::percent_encode;
 use crate::Side;
+use futures_util::stream::{self, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 /// Client for CLOB (Central Limit Order Book) market data APIs
 ///
@@ -14,6 +21,12 @@ use crate::Side;
 /// without requiring authentication.
 pub struct ClobClient {
     http_client: HttpClient,
+    /// Cached by [`tick_size`](Self::tick_size)
+    tick_sizes: TtlCache<TokenId, Decimal>,
+    /// Cached by [`neg_risk`](Self::neg_risk)
+    neg_risks: TtlCache<TokenId, bool>,
+    /// Cached by [`get_fee_rate`](Self::get_fee_rate)
+    fee_rates: TtlCache<TokenId, FeeRate>,
 }
 
 impl ClobClient {
@@ -24,9 +37,23 @@ impl ClobClient {
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
+            tick_sizes: TtlCache::new(None),
+            neg_risks: TtlCache::new(None),
+            fee_rates: TtlCache::new(None),
         }
     }
 
+    /// Use a custom HTTP client (e.g. one built with [`HttpClient::builder`])
+    ///
+    /// Requests then go through `http_client` with its retry policy, timeouts,
+    /// proxy, rate limits and default headers. Its base URL replaces the
+    /// `host` passed to [`new`](Self::new). The other clients take one the
+    /// same way.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
     /// Check if the server is responsive
     pub async fn get_ok(&self) -> Result<serde_json::Value> {
         self.http_client.get("/", None).await
@@ -46,15 +73,19 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get midpoint prices for multiple tokens
+    /// Get midpoint prices for multiple tokens in one request
+    ///
+    /// Tokens the API has no midpoint for (e.g. without an order book) are
+    /// omitted from the returned map.
     ///
     /// # Arguments
     /// * `token_ids` - List of token IDs to query
-    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<Vec<MidpointResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/midpoints", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<HashMap<TokenId, Decimal>> {
+        let body: Vec<_> = token_ids
+            .iter()
+            .map(|id| serde_json::json!({ "token_id": id.as_str() }))
+            .collect();
+        self.http_client.post("/midpoints", &body, None).await
     }
 
     /// Get the current price for a token
@@ -68,12 +99,18 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get prices for multiple tokens
-    pub async fn get_prices(&self, token_ids: &[TokenId]) -> Result<Vec<PriceResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/prices", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    /// Get prices for multiple tokens and sides in one request
+    ///
+    /// The result maps each token ID to its price on every requested side.
+    /// Tokens the API doesn't return are omitted from the map.
+    ///
+    /// # Arguments
+    /// * `requests` - Token ID and side pairs to query
+    pub async fn get_prices(
+        &self,
+        requests: &[BookParams],
+    ) -> Result<HashMap<TokenId, HashMap<Side, Decimal>>> {
+        self.http_client.post("/prices", &requests, None).await
     }
 
     /// Get price history for a token
@@ -124,24 +161,220 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get whether the market of a token uses negative risk
+    pub async fn get_token_neg_risk(&self, token_id: &TokenId) -> Result<NegRiskResponse> {
+        let path = format!("/neg-risk?token_id={}", token_id.as_str());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get whether a market uses negative risk
+    #[deprecated(note = "the CLOB looks up neg risk by token, use `get_token_neg_risk`")]
     pub async fn get_neg_risk(&self, condition_id: &ConditionId) -> Result<NegRiskResponse> {
         let path = format!("/neg-risk?condition_id={}", condition_id.as_str());
         self.http_client.get(&path, None).await
     }
 
+    /// Minimum tick size for a token, fetched once and then cached
+    ///
+    /// Use [`invalidate_market_params`](Self::invalidate_market_params) when
+    /// the market changes its tick size, e.g. on a `tick_size_change` event.
+    pub async fn tick_size(&self, token_id: &TokenId) -> Result<Decimal> {
+        if let Some(tick_size) = self.tick_sizes.get(token_id) {
+            return Ok(tick_size);
+        }
+        let tick_size = self.get_tick_size(token_id).await?.minimum_tick_size;
+        self.tick_sizes.insert(token_id.clone(), tick_size);
+        Ok(tick_size)
+    }
+
+    /// Whether the market of a token uses negative risk, fetched once and then cached
+    pub async fn neg_risk(&self, token_id: &TokenId) -> Result<bool> {
+        if let Some(neg_risk) = self.neg_risks.get(token_id) {
+            return Ok(neg_risk);
+        }
+        let neg_risk = self.get_token_neg_risk(token_id).await?.neg_risk;
+        self.neg_risks.insert(token_id.clone(), neg_risk);
+        Ok(neg_risk)
+    }
+
+    /// Fee rates of the market of a token, fetched once and then cached
+    ///
+    /// The exchange reports a single base rate per market, which applies to
+    /// makers and takers alike. When it has no rate for the market (404), the
+    /// result falls back to [`DEFAULT_FEE_RATE_BPS`] with `estimated` set;
+    /// the fallback is cached too.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::ClobClient;
+    /// use polymarket_rs::orders::estimate_order_cost;
+    /// use polymarket_rs::types::TokenId;
+    /// use polymarket_rs::Side;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = ClobClient::new("https://clob.polymarket.com");
+    /// let fee_rate = client.get_fee_rate(&TokenId::new("123456")).await?;
+    /// let cost = estimate_order_cost(dec!(0.4), dec!(100), Side::Buy, fee_rate.taker_bps)?;
+    /// println!("Cost: {} (estimated fee: {})", cost.net, fee_rate.estimated);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_fee_rate(&self, token_id: &TokenId) -> Result<FeeRate> {
+        if let Some(fee_rate) = self.fee_rates.get(token_id) {
+            return Ok(fee_rate);
+        }
+
+        let path = format!("/fee-rate?token_id={}", token_id.as_str());
+        let fee_rate = match self.http_client.get::<FeeRateResponse>(&path, None).await {
+            Ok(response) => FeeRate {
+                maker_bps: response.base_fee,
+                taker_bps: response.base_fee,
+                estimated: false,
+            },
+            Err(Error::Api { status: 404, .. }) => FeeRate {
+                maker_bps: DEFAULT_FEE_RATE_BPS,
+                taker_bps: DEFAULT_FEE_RATE_BPS,
+                estimated: true,
+            },
+            Err(e) => return Err(e),
+        };
+        self.fee_rates.insert(token_id.clone(), fee_rate);
+        Ok(fee_rate)
+    }
+
+    /// Order options (tick size and neg risk) for a token from the cache
+    ///
+    /// Fetches whichever of the two isn't cached yet.
+    pub async fn order_options(&self, token_id: &TokenId) -> Result<CreateOrderOptions> {
+        Ok(CreateOrderOptions::new()
+            .tick_size(self.tick_size(token_id).await?)
+            .neg_risk(self.neg_risk(token_id).await?))
+    }
+
+    /// Round a limit order to the cached tick size of its token
+    ///
+    /// The price is rounded with [`round_to_tick`], i.e. as a resting (maker)
+    /// order, and the size with [`round_size`], using the size precision of
+    /// the tick size. Round a marketable price with
+    /// [`round_to_tick_taker`](crate::orders::round_to_tick_taker) instead.
+    ///
+    /// # Arguments
+    /// * `order_args` - The order to round
+    /// * `min_order_size` - Minimum size of the market (see [`Market::minimum_order_size`])
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`](crate::Error::InvalidOrder) if the rounded size is below
+    /// `min_order_size`.
+    pub async fn round_order_args(
+        &self,
+        order_args: &OrderArgs,
+        min_order_size: Decimal,
+    ) -> Result<OrderArgs> {
+        let tick_size = self
+            .tick_size(&TokenId::new(order_args.token_id.clone()))
+            .await?;
+        let size_decimals = ROUNDING_CONFIG
+            .get(&tick_size)
+            .map_or(2, |config| config.size);
+
+        Ok(OrderArgs {
+            price: round_to_tick(order_args.price, tick_size, order_args.side),
+            size: round_size(order_args.size, min_order_size, size_decimals)?,
+            ..order_args.clone()
+        })
+    }
+
+    /// Drop the cached tick size, neg risk flag and fee rate of a token
+    pub fn invalidate_market_params(&self, token_id: &TokenId) {
+        self.tick_sizes.invalidate(token_id);
+        self.neg_risks.invalidate(token_id);
+        self.fee_rates.invalidate(token_id);
+    }
+
+    /// Drop all cached tick sizes, neg risk flags and fee rates
+    pub fn clear_market_params_cache(&self) {
+        self.tick_sizes.clear();
+        self.neg_risks.clear();
+        self.fee_rates.clear();
+    }
+
     /// Get the order book for a token
     ///
+    /// Bids are sorted descending and asks ascending, ready for
+    /// [`calculate_market_price`](crate::orders::calculate_market_price).
+    ///
     /// # Arguments
     /// * `token_id` - The token ID to query
     pub async fn get_order_book(&self, token_id: &TokenId) -> Result<OrderBookSummary> {
+        self.get_order_book_with_depth(token_id, None).await
+    }
+
+    /// Get the order book for a token along with its best bid, best ask, mid and spread
+    ///
+    /// Fetches the book like [`get_order_book`](Self::get_order_book) and
+    /// derives the prices with [`best_bid`](crate::orders::best_bid),
+    /// [`best_ask`](crate::orders::best_ask), [`mid_price`](crate::orders::mid_price)
+    /// and [`spread`](crate::orders::spread).
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    pub async fn get_order_book_summary(&self, token_id: &TokenId) -> Result<OrderBookStats> {
+        Ok(OrderBookStats::new(self.get_order_book(token_id).await?))
+    }
+
+    /// Get the order book for a token, keeping only the top `depth` levels per side
+    ///
+    /// The `/book` endpoint has no depth parameter and always returns the full
+    /// book, so the levels beyond `depth` are dropped client-side after
+    /// sorting; it saves work for callers, not bandwidth. `None` keeps every level.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    /// * `depth` - Number of levels to keep on each side
+    pub async fn get_order_book_with_depth(
+        &self,
+        token_id: &TokenId,
+        depth: Option<usize>,
+    ) -> Result<OrderBookSummary> {
         let path = format!("/book?token_id={}", token_id.as_str());
-        self.http_client.get(&path, None).await
+        let mut book: OrderBookSummary = self.http_client.get(&path, None).await?;
+        match depth {
+            Some(depth) => book.truncate(depth),
+            None => book.sort(),
+        }
+        Ok(book)
     }
 
-    /// Get order books for multiple tokens
+    /// Get order books for multiple tokens, sorted like [`get_order_book`](Self::get_order_book)
     pub async fn get_order_books(&self, params: &[BookParams]) -> Result<Vec<OrderBookSummary>> {
-        self.http_client.post("/books", &params, None).await
+        let mut books: Vec<OrderBookSummary> =
+            self.http_client.post("/books", &params, None).await?;
+        books.iter_mut().for_each(OrderBookSummary::sort);
+        Ok(books)
+    }
+
+    /// Fetch order books for several tokens with concurrent `/book` requests
+    ///
+    /// At most `concurrency` requests are in flight at once (at least one), so
+    /// large batches don't trip the rate limiter. A failure for one token is
+    /// stored under its ID instead of failing the whole batch. Use
+    /// [`get_order_books`](Self::get_order_books) to fetch them in a single request.
+    ///
+    /// # Arguments
+    /// * `token_ids` - The token IDs to query
+    /// * `concurrency` - Maximum number of requests in flight
+    pub async fn get_order_books_concurrent(
+        &self,
+        token_ids: &[TokenId],
+        concurrency: usize,
+    ) -> HashMap<TokenId, Result<OrderBookSummary>> {
+        stream::iter(token_ids)
+            .map(|token_id| async move { (token_id.clone(), self.get_order_book(token_id).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Get the last trade price for a token
@@ -170,15 +403,13 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/sampling-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        pagination
+            .as_ref()
+            .map(PaginationParams::validate)
+            .transpose()?;
+        self.http_client
+            .get_with_query("/sampling-markets", &pagination, None)
+            .await
     }
 
     /// Get sampling simplified markets with pagination
@@ -189,15 +420,13 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/sampling-simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        pagination
+            .as_ref()
+            .map(PaginationParams::validate)
+            .transpose()?;
+        self.http_client
+            .get_with_query("/sampling-simplified-markets", &pagination, None)
+            .await
     }
 
     /// Get markets with pagination
@@ -208,15 +437,13 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        pagination
+            .as_ref()
+            .map(PaginationParams::validate)
+            .transpose()?;
+        self.http_client
+            .get_with_query("/markets", &pagination, None)
+            .await
     }
 
     /// Get simplified markets with pagination
@@ -224,15 +451,13 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        pagination
+            .as_ref()
+            .map(PaginationParams::validate)
+            .transpose()?;
+        self.http_client
+            .get_with_query("/simplified-markets", &pagination, None)
+            .await
     }
 
     /// Get a specific market by condition ID
@@ -247,6 +472,35 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get liquidity rewards programs with pagination
+    ///
+    /// Without a market filter only markets with a running epoch are
+    /// returned. The endpoint can't filter by date or epoch; check the
+    /// `start_date` and `end_date` of each [`RewardsConfig`](crate::types::RewardsConfig)
+    /// instead. Use [`paginate_all`](crate::request::paginate_all) to walk
+    /// every page.
+    ///
+    /// # Arguments
+    /// * `params` - Optional market filter
+    /// * `pagination` - Pagination parameters
+    pub async fn get_rewards(
+        &self,
+        params: &RewardsParams,
+        pagination: Option<PaginationParams>,
+    ) -> Result<RewardsResponse> {
+        pagination
+            .as_ref()
+            .map(PaginationParams::validate)
+            .transpose()?;
+        let path = match &params.condition_id {
+            Some(condition_id) => format!("/rewards/markets/{}", percent_encode(condition_id)),
+            None => "/rewards/markets/current".to_string(),
+        };
+        self.http_client
+            .get_with_query(&path, &pagination, None)
+            .await
+    }
+
     /// Get live activity events for a market (trades and events)
     ///
     /// # Arguments
@@ -259,3 +513,312 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_get_markets_rejects_invalid_limit_before_sending() {
+        let server = TestServer::start(vec![]).await;
+        let client = ClobClient::new(&server.url);
+
+        let mut pagination = PaginationParams::initial().with_max_limit(10);
+        pagination.limit = Some(20);
+
+        let result = client.get_markets(Some(pagination)).await;
+        assert!(matches!(result, Err(crate::Error::InvalidParameter(_))));
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_with_depth_keeps_best_levels() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"market":"m","asset_id":"1","hash":"h","timestamp":"0",
+                "bids":[{"price":"0.4","size":"1"},{"price":"0.45","size":"2"},{"price":"0.47","size":"3"}],
+                "asks":[{"price":"0.6","size":"1"},{"price":"0.55","size":"2"},{"price":"0.5","size":"3"}]}"#,
+        )])
+        .await;
+        let client = ClobClient::new(&server.url);
+
+        let book = client
+            .get_order_book_with_depth(&TokenId::new("1"), Some(2))
+            .await
+            .unwrap();
+        let prices = |levels: &[crate::types::PriceLevel]| {
+            levels.iter().map(|l| l.price).collect::<Vec<_>>()
+        };
+        assert_eq!(prices(&book.bids), vec![dec!(0.47), dec!(0.45)]);
+        assert_eq!(prices(&book.asks), vec![dec!(0.5), dec!(0.55)]);
+        assert_eq!(server.requests()[0].path, "/book?token_id=1");
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_summary() {
+        let server = TestServer::start(vec![
+            MockResponse::json(
+                200,
+                r#"{"market":"m","asset_id":"1","hash":"h","timestamp":"0",
+                    "bids":[{"price":"0.4","size":"1"},{"price":"0.45","size":"2"}],
+                    "asks":[{"price":"0.6","size":"1"},{"price":"0.5","size":"3"}]}"#,
+            ),
+            MockResponse::json(
+                200,
+                r#"{"market":"m","asset_id":"1","hash":"h","timestamp":"0",
+                    "bids":[{"price":"0.4","size":"1"}],"asks":[]}"#,
+            ),
+        ])
+        .await;
+        let client = ClobClient::new(&server.url);
+        let token_id = TokenId::new("1");
+
+        let summary = client.get_order_book_summary(&token_id).await.unwrap();
+        assert_eq!(summary.best_bid, Some(dec!(0.45)));
+        assert_eq!(summary.best_ask, Some(dec!(0.5)));
+        assert_eq!(summary.mid, Some(dec!(0.475)));
+        assert_eq!(summary.spread, Some(dec!(0.05)));
+        assert_eq!(summary.book.asks[0].price, dec!(0.5));
+
+        let one_sided = client.get_order_book_summary(&token_id).await.unwrap();
+        assert_eq!(one_sided.best_bid, Some(dec!(0.4)));
+        assert_eq!(one_sided.best_ask, None);
+        assert_eq!(one_sided.mid, None);
+        assert_eq!(one_sided.spread, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_books_concurrent_keeps_per_token_errors() {
+        let book = |id: &str| {
+            format!(
+                r#"{{"market":"m","asset_id":"{}","hash":"h","timestamp":"0","bids":[{{"price":"0.4","size":"1"}},{{"price":"0.45","size":"1"}}],"asks":[]}}"#,
+                id
+            )
+        };
+        let server = TestServer::start(vec![
+            MockResponse::json(200, &book("1")),
+            MockResponse::json(
+                404,
+                r#"{"error":"No orderbook exists for the requested token id"}"#,
+            ),
+        ])
+        .await;
+        let client = ClobClient::new(&server.url);
+
+        // One request at a time so the mock responses line up with the tokens
+        let ids = [TokenId::new("1"), TokenId::new("2")];
+        let books = client.get_order_books_concurrent(&ids, 1).await;
+
+        assert_eq!(books.len(), 2);
+        let first = books[&ids[0]].as_ref().unwrap();
+        assert_eq!(first.bids[0].price.to_string(), "0.45");
+        assert!(books[&ids[1]].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoints_map() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"1": "0.45", "2": "0.7"}"#,
+        )])
+        .await;
+        let client = ClobClient::new(&server.url);
+
+        let ids = [TokenId::new("1"), TokenId::new("2"), TokenId::new("3")];
+        let midpoints = client.get_midpoints(&ids).await.unwrap();
+
+        assert_eq!(midpoints.len(), 2);
+        assert_eq!(midpoints[&ids[0]].to_string(), "0.45");
+        assert!(!midpoints.contains_key(&ids[2]));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/midpoints");
+        assert_eq!(
+            request.body,
+            r#"[{"token_id":"1"},{"token_id":"2"},{"token_id":"3"}]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_map() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"1": {"BUY": "0.44", "SELL": "0.46"}}"#,
+        )])
+        .await;
+        let client = ClobClient::new(&server.url);
+
+        let requests = [
+            BookParams::new("1", Side::Buy),
+            BookParams::new("1", Side::Sell),
+        ];
+        let prices = client.get_prices(&requests).await.unwrap();
+
+        let token = &prices[&TokenId::new("1")];
+        assert_eq!(token[&Side::Buy].to_string(), "0.44");
+        assert_eq!(token[&Side::Sell].to_string(), "0.46");
+        assert_eq!(
+            server.requests()[0].body,
+            r#"[{"token_id":"1","side":"BUY"},{"token_id":"1","side":"SELL"}]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_sorts_levels() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{
+                "market": "0xabc",
+                "asset_id": "123",
+                "hash": "0xhash",
+                "timestamp": "1700000000000",
+                "bids": [{"price": "0.40", "size": "10"}, {"price": "0.45", "size": "5"}],
+                "asks": [{"price": "0.60", "size": "10"}, {"price": "0.55", "size": "5"}]
+            }"#,
+        )])
+        .await;
+
+        let client = ClobClient::new(&server.url);
+        let book = client.get_order_book(&TokenId::new("123")).await.unwrap();
+
+        assert_eq!(book.hash, "0xhash");
+        assert_eq!(book.timestamp, 1700000000000);
+        assert_eq!(book.bids[0].price.to_string(), "0.45");
+        assert_eq!(book.asks[0].price.to_string(), "0.55");
+        assert_eq!(server.requests()[0].path, "/book?token_id=123");
+    }
+
+    #[tokio::test]
+    async fn test_sorted_book_verifies_server_hash() {
+        // Levels in the order `/book` sends them: bids ascending, asks descending
+        let bids = [("0.45", "120"), ("0.47", "30.5"), ("0.48", "200")];
+        let asks = [("0.55", "80"), ("0.53", "15"), ("0.52", "42")];
+        let levels = |side: &[(&str, &str)]| -> Vec<crate::types::PriceLevel> {
+            side.iter()
+                .map(|(price, size)| (price.parse().unwrap(), size.parse().unwrap()).into())
+                .collect()
+        };
+        let hash = crate::orders::compute_book_hash(
+            "0xabc",
+            "123",
+            1700000000000,
+            &levels(&bids),
+            &levels(&asks),
+        );
+        let json = serde_json::json!({
+            "market": "0xabc",
+            "asset_id": "123",
+            "hash": hash,
+            "timestamp": "1700000000000",
+            "bids": levels(&bids),
+            "asks": levels(&asks),
+        });
+        let server = TestServer::start(vec![MockResponse::json(200, &json.to_string())]).await;
+
+        let client = ClobClient::new(&server.url);
+        let mut book = client.get_order_book(&TokenId::new("123")).await.unwrap();
+        assert_eq!(book.bids[0].price.to_string(), "0.48");
+        assert!(book.verify_hash());
+
+        book.truncate(2);
+        assert!(!book.verify_hash());
+    }
+
+    #[tokio::test]
+    async fn test_market_params_are_cached_until_invalidated() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, r#"{"minimum_tick_size":"0.01"}"#),
+            MockResponse::json(200, r#"{"neg_risk":true}"#),
+            MockResponse::json(200, r#"{"minimum_tick_size":"0.001"}"#),
+        ])
+        .await;
+        let client = ClobClient::new(&server.url);
+        let token_id = TokenId::new("1");
+
+        let options = client.order_options(&token_id).await.unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.01)));
+        assert_eq!(options.neg_risk, Some(true));
+
+        let args = OrderArgs::new("1", dec!(0.456), dec!(10.129), Side::Buy);
+        let rounded = client.round_order_args(&args, dec!(5)).await.unwrap();
+        assert_eq!(rounded.price, dec!(0.45));
+        assert_eq!(rounded.size, dec!(10.12));
+        assert_eq!(server.requests().len(), 2);
+
+        client.invalidate_market_params(&token_id);
+        assert_eq!(client.tick_size(&token_id).await.unwrap(), dec!(0.001));
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/tick-size?token_id=1",
+                "/neg-risk?token_id=1",
+                "/tick-size?token_id=1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fee_rate_is_cached_with_fallback() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, r#"{"base_fee":200}"#),
+            MockResponse::json(404, r#"{"error":"fee rate not found"}"#),
+        ])
+        .await;
+        let client = ClobClient::new(&server.url);
+        let (priced, unpriced) = (TokenId::new("1"), TokenId::new("2"));
+
+        let fee_rate = client.get_fee_rate(&priced).await.unwrap();
+        assert_eq!(fee_rate.taker_bps, 200);
+        assert!(!fee_rate.estimated);
+        // Served from the cache
+        assert_eq!(client.get_fee_rate(&priced).await.unwrap(), fee_rate);
+        assert_eq!(server.requests().len(), 1);
+
+        let fallback = client.get_fee_rate(&unpriced).await.unwrap();
+        assert_eq!(fallback.maker_bps, DEFAULT_FEE_RATE_BPS);
+        assert!(fallback.estimated);
+        assert!(client.get_fee_rate(&unpriced).await.unwrap().estimated);
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(paths, vec!["/fee-rate?token_id=1", "/fee-rate?token_id=2"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_rewards() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"limit":1,"count":1,"next_cursor":"LTE=","data":[{"condition_id":"0xabc",
+                "rewards_max_spread":3,"rewards_min_size":50,"rewards_config":[]}]}"#,
+        )])
+        .await;
+        let client = ClobClient::new(&server.url);
+
+        let page = client
+            .get_rewards(&RewardsParams::new(), Some(PaginationParams::initial()))
+            .await
+            .unwrap();
+        assert_eq!(page.data[0].rewards_min_size, dec!(50));
+        client
+            .get_rewards(&RewardsParams::new().condition_id("0xabc"), None)
+            .await
+            .unwrap();
+        client
+            .get_rewards(&RewardsParams::new().condition_id("0x1/../x"), None)
+            .await
+            .unwrap();
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/rewards/markets/current?next_cursor=MA%3D%3D",
+                "/rewards/markets/0xabc",
+                "/rewards/markets/0x1%2F..%2Fx"
+            ]
+        );
+    }
+}
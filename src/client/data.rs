@@ -1,6 +1,6 @@
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::{ActivityQueryParams, TradeQueryParams};
+use crate::request::{ActivityQueryParams, PositionQueryParams, TradeQueryParams};
 use crate::types::{Activity, ClosedPosition, Position, PositionValue, Trade};
 
 /// Client for accessing position and portfolio data
@@ -22,16 +22,27 @@ impl DataClient {
         }
     }
 
-    /// Get all positions for a user
+    /// Use a custom HTTP client, see [`ClobClient::with_http_client`](crate::client::ClobClient::with_http_client)
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Get current positions for a user
     ///
     /// # Arguments
     /// * `user` - The user's wallet address
+    /// * `params` - Optional query parameters (limit, offset, market, size_threshold, redeemable, mergeable, sorting)
     ///
     /// # Returns
     /// A list of positions owned by the user
-    pub async fn get_positions(&self, user: &str) -> Result<Vec<Position>> {
+    pub async fn get_positions(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+    ) -> Result<Vec<Position>> {
         let path = format!("/positions?user={}", user);
-        self.http_client.get(&path, None).await
+        self.http_client.get_with_query(&path, &params, None).await
     }
 
     /// Get the total value of positions for a user
@@ -50,7 +61,7 @@ impl DataClient {
     ///
     /// # Arguments
     /// * `user` - User wallet address to filter trades
-    /// * `params` - Optional query parameters (limit, offset, taker_only)
+    /// * `params` - Optional query parameters (limit, offset, taker_only, market, asset_id, maker/taker address)
     ///
     /// # Returns
     /// A list of recent trades
@@ -59,37 +70,32 @@ impl DataClient {
         user: &str,
         params: Option<TradeQueryParams>,
     ) -> Result<Vec<Trade>> {
-        let mut path = format!("/trades?user={}", user);
-
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
+        if let Some(params) = &params {
+            params.validate()?;
         }
-
-        println!("{}", path);
-
-        self.http_client.get(&path, None).await
+        let path = format!("/trades?user={}", user);
+        self.http_client.get_with_query(&path, &params, None).await
     }
 
     /// Get recent activity
     ///
     /// # Arguments
     /// * `user` - User wallet address to filter activity
-    /// * `params` - Optional query parameters (limit, offset, sort_by, sort_direction)
+    /// * `params` - Optional query parameters (limit, offset, sort_by, sort_direction, start_ts, end_ts)
     ///
     /// # Returns
-    /// A list of recent activity events
+    /// A list of recent activity events, or [`Error::InvalidParameter`](crate::Error::InvalidParameter)
+    /// if `start_ts` is after `end_ts`
     pub async fn get_activity(
         &self,
         user: &str,
         params: Option<ActivityQueryParams>,
     ) -> Result<Vec<Activity>> {
-        let mut path = format!("/activity?user={}", user);
-
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
+        if let Some(params) = &params {
+            params.validate()?;
         }
-
-        self.http_client.get(&path, None).await
+        let path = format!("/activity?user={}", user);
+        self.http_client.get_with_query(&path, &params, None).await
     }
 
     /// Get closed positions
@@ -104,3 +110,54 @@ impl DataClient {
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+    use crate::request::PositionSortBy;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_get_positions() {
+        // Optional metadata (eventId, endDate, opposite outcome) is omitted
+        let body = r#"[{
+            "proxyWallet": "0xabc",
+            "asset": "123",
+            "conditionId": "0xc0",
+            "size": 10.5,
+            "avgPrice": 0.42,
+            "initialValue": 4.41,
+            "currentValue": 5.25,
+            "cashPnl": 0.84,
+            "percentPnl": 19.04,
+            "totalBought": 10.5,
+            "realizedPnl": 0,
+            "percentRealizedPnl": 0,
+            "curPrice": 0.5,
+            "title": "Will it rain?",
+            "outcome": "Yes",
+            "outcomeIndex": 0
+        }]"#;
+        let server = TestServer::start(vec![MockResponse::json(200, body)]).await;
+        let client = DataClient::new(&server.url);
+
+        let params = PositionQueryParams::new()
+            .with_limit(10)
+            .with_sort_by(PositionSortBy::Current);
+        let positions = client.get_positions("0xabc", Some(params)).await.unwrap();
+
+        let position = &positions[0];
+        assert_eq!(position.size, dec!(10.5));
+        assert_eq!(position.avg_price, dec!(0.42));
+        assert_eq!(position.current_value, dec!(5.25));
+        assert_eq!(position.cash_pnl, dec!(0.84));
+        assert!(position.end_date.is_none());
+        assert!(!position.redeemable);
+
+        assert_eq!(
+            server.requests()[0].path,
+            "/positions?user=0xabc&limit=10&sortBy=CURRENT"
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use crate::error::Result;
+use super::cache::TtlCache;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
-use crate::request::GammaMarketParams;
+use crate::request::{GammaEventParams, GammaMarketParams};
 use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
+use crate::utils::percent_encode;
+use std::time::Duration;
 
 /// Client for Gamma API - Market discovery and metadata
 ///
@@ -34,9 +37,13 @@ use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag
 /// ```
 pub struct GammaClient {
     http_client: HttpClient,
+    condition_ids: TtlCache<String, String>,
 }
 
 impl GammaClient {
+    /// How long [`resolve_condition_id`](Self::resolve_condition_id) caches a mapping by default
+    const DEFAULT_CONDITION_ID_TTL: Duration = Duration::from_secs(60 * 60);
+
     /// Create a new GammaClient
     ///
     /// # Arguments
@@ -51,9 +58,22 @@ impl GammaClient {
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
+            condition_ids: TtlCache::new(Some(Self::DEFAULT_CONDITION_ID_TTL)),
         }
     }
 
+    /// Use a custom HTTP client, see [`ClobClient::with_http_client`](crate::client::ClobClient::with_http_client)
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Set how long resolved condition IDs are cached (`None` = forever)
+    pub fn with_condition_id_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.condition_ids.set_ttl(ttl);
+        self
+    }
+
     /// Get markets with optional filtering and pagination
     ///
     /// # Arguments
@@ -78,9 +98,7 @@ impl GammaClient {
     ///
     /// let markets = client.get_markets(Some(params)).await?;
     /// for market in markets {
-    ///     if let Some(q) = &market.question {
-    ///         println!("{}: {}", market.id, q);
-    ///     }
+    ///     println!("{}: {}", market.id, market.question);
     /// }
     /// # Ok(())
     /// # }
@@ -89,11 +107,9 @@ impl GammaClient {
         &self,
         params: Option<GammaMarketParams>,
     ) -> Result<Vec<GammaMarket>> {
-        let mut path = "/markets".to_string();
-        if let Some(p) = params {
-            path.push_str(&p.to_query_string());
-        }
-        self.http_client.get(&path, None).await
+        self.http_client
+            .get_with_query("/markets", &params, None)
+            .await
     }
 
     /// Get a specific market by condition ID
@@ -112,9 +128,7 @@ impl GammaClient {
     /// # async fn main() -> polymarket_rs::Result<()> {
     /// let client = GammaClient::new("https://gamma-api.polymarket.com");
     /// let market = client.get_market("0x123...").await?;
-    /// if let Some(q) = &market.question {
-    ///     println!("Market: {}", q);
-    /// }
+    /// println!("Market: {}", market.question);
     /// # Ok(())
     /// # }
     /// ```
@@ -123,6 +137,54 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Resolve the condition ID of the market a token (asset) belongs to
+    ///
+    /// Useful with events that only carry an asset ID, such as trades from the
+    /// WebSocket. Mappings are cached in memory for an hour by default (see
+    /// [`with_condition_id_ttl`](Self::with_condition_id_ttl)), so repeated
+    /// lookups for the same asset don't hit the API.
+    ///
+    /// # Arguments
+    /// * `asset_id` - The CLOB token ID
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let condition_id = client.resolve_condition_id("123456").await?;
+    /// let market = client.get_market(&condition_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_condition_id(&self, asset_id: &str) -> Result<String> {
+        let asset_id = asset_id.to_string();
+        if let Some(condition_id) = self.condition_ids.get(&asset_id) {
+            return Ok(condition_id);
+        }
+
+        let params = GammaMarketParams::new().with_clob_token_id(asset_id.clone());
+        let market = self
+            .get_markets(Some(params))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!("No market found for asset ID {}", asset_id))
+            })?;
+
+        self.condition_ids
+            .insert(asset_id, market.condition_id.clone());
+        Ok(market.condition_id)
+    }
+
+    /// Drop all cached condition IDs
+    pub fn clear_condition_id_cache(&self) {
+        self.condition_ids.clear();
+    }
+
     /// Get all available tags
     ///
     /// Tags are used for categorizing and filtering markets. This endpoint returns
@@ -200,13 +262,54 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get all events
+    /// Get a market by its slug, the human-readable name in its URL
+    ///
+    /// The slug is percent-encoded into the path as given. For a URL pasted
+    /// from the website use [`get_market_by_url`](Self::get_market_by_url).
+    ///
+    /// # Arguments
+    /// * `slug` - The market slug, e.g. `will-it-rain-tomorrow`
+    ///
+    /// # Returns
+    /// The market, or `None` if no market has this slug
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// if let Some(market) = client.get_market_by_slug("will-it-rain-tomorrow").await? {
+    ///     println!("Market: {}", market.question);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_market_by_slug(&self, slug: &str) -> Result<Option<GammaMarket>> {
+        if slug.trim().is_empty() {
+            return Err(Error::InvalidParameter(
+                "slug must not be empty".to_string(),
+            ));
+        }
+
+        let path = format!("/markets/slug/{}", percent_encode(slug));
+        match self.http_client.get(&path, None).await {
+            Ok(market) => Ok(Some(market)),
+            Err(Error::Api { status: 404, .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get a market from a Polymarket URL
     ///
-    /// Events are collections of related markets. This endpoint returns
-    /// all events available in the Gamma API.
+    /// Accepts market URLs of the form `https://polymarket.com/event/<event>/<market>`
+    /// or `https://polymarket.com/market/<market>`; the query and fragment are
+    /// ignored. A URL naming only an event is rejected, as an event may hold
+    /// several markets.
     ///
     /// # Returns
-    /// A list of all events with their metadata
+    /// The market, or `None` if no market has the slug in the URL
     ///
     /// # Example
     /// ```no_run
@@ -215,13 +318,52 @@ impl GammaClient {
     /// # #[tokio::main]
     /// # async fn main() -> polymarket_rs::Result<()> {
     /// let client = GammaClient::new("https://gamma-api.polymarket.com");
-    /// let events = client.get_events().await?;
+    /// let url = "https://polymarket.com/event/weather/will-it-rain-tomorrow";
+    /// if let Some(market) = client.get_market_by_url(url).await? {
+    ///     println!("Market: {}", market.question);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_market_by_url(&self, url: &str) -> Result<Option<GammaMarket>> {
+        let slug = market_slug_from_url(url).ok_or_else(|| {
+            Error::InvalidParameter(format!("not a Polymarket market URL: {}", url))
+        })?;
+        self.get_market_by_slug(slug).await
+    }
+
+    /// Get events with optional filtering and pagination
+    ///
+    /// Events are collections of related markets, carrying the human-readable
+    /// title, description, tags and end date shared by those markets.
+    ///
+    /// # Arguments
+    /// * `params` - Optional query parameters for filtering and pagination
+    ///
+    /// # Returns
+    /// A list of events with their metadata and nested markets
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    /// use polymarket_rs::request::GammaEventParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let params = GammaEventParams::new()
+    ///     .with_active(true)
+    ///     .with_limit(10);
+    ///
+    /// let events = client.get_events(Some(params)).await?;
     /// println!("Found {} events", events.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_events(&self) -> Result<Vec<GammaEvent>> {
-        self.http_client.get("/events", None).await
+    pub async fn get_events(&self, params: Option<GammaEventParams>) -> Result<Vec<GammaEvent>> {
+        self.http_client
+            .get_with_query("/events", &params, None)
+            .await
     }
 
     /// Get a specific event by its ID
@@ -298,3 +440,159 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 }
+
+/// Market slug of a `/event/<event>/<market>` or `/market/<market>` URL
+fn market_slug_from_url(url: &str) -> Option<&str> {
+    let (_, rest) = url.trim().split_once("://")?;
+    let path = rest.split(['?', '#']).next()?;
+    let segments: Vec<&str> = path.split('/').skip(1).filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        ["event", _, market] | ["market", market] => Some(market),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+
+    #[tokio::test]
+    async fn test_get_events_with_params() {
+        let body = r#"[{
+            "id": "1",
+            "ticker": "rain",
+            "slug": "rain",
+            "title": "Will it rain?",
+            "description": "Resolves YES if it rains.",
+            "endDate": "2025-01-01T00:00:00Z",
+            "volume": 1234.5,
+            "tags": [{"id": "7", "label": "Weather", "slug": "weather"}],
+            "markets": []
+        }]"#;
+        let server = TestServer::start(vec![MockResponse::json(200, body)]).await;
+        let client = GammaClient::new(&server.url);
+
+        let params = GammaEventParams::new()
+            .with_active(true)
+            .with_limit(5)
+            .with_offset(10);
+        let events = client.get_events(Some(params)).await.unwrap();
+
+        let event = &events[0];
+        assert_eq!(event.title, "Will it rain?");
+        assert_eq!(
+            event.description.as_deref(),
+            Some("Resolves YES if it rains.")
+        );
+        assert_eq!(event.tags[0].label, "Weather");
+        assert!(event.end_date.is_some());
+        assert_eq!(event.volume, Some(1234.5));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/events?limit=5&offset=10&active=true");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_condition_id_is_cached() {
+        let body = r#"[{
+            "id": "1",
+            "question": "Will it rain?",
+            "description": "",
+            "conditionId": "0xabc",
+            "clobTokenIds": "[\"111\", \"222\"]",
+            "slug": "rain"
+        }]"#;
+        let server = TestServer::start(vec![
+            MockResponse::json(200, body),
+            MockResponse::json(200, "[]"),
+        ])
+        .await;
+        let client = GammaClient::new(&server.url);
+
+        assert_eq!(client.resolve_condition_id("111").await.unwrap(), "0xabc");
+        assert_eq!(client.resolve_condition_id("111").await.unwrap(), "0xabc");
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(server.requests()[0].path, "/markets?clob_token_ids=111");
+
+        let err = client.resolve_condition_id("999").await.unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_by_slug() {
+        let body = r#"{
+            "id": "1",
+            "question": "Will it rain?",
+            "description": "",
+            "conditionId": "0xabc",
+            "slug": "will-it-rain"
+        }"#;
+        let server = TestServer::start(vec![
+            MockResponse::json(200, body),
+            MockResponse::json(404, r#"{"error":"not found"}"#),
+        ])
+        .await;
+        let client = GammaClient::new(&server.url);
+
+        let market = client
+            .get_market_by_slug("will-it-rain")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(market.condition_id, "0xabc");
+        assert_eq!(server.requests()[0].path, "/markets/slug/will-it-rain");
+
+        assert!(client
+            .get_market_by_slug("no such/?")
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(server.requests()[1].path, "/markets/slug/no%20such%2F%3F");
+
+        assert!(matches!(
+            client.get_market_by_slug(" ").await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_by_url() {
+        let body = r#"{
+            "id": "1",
+            "question": "Will it rain?",
+            "description": "",
+            "conditionId": "0xabc",
+            "slug": "will-it-rain"
+        }"#;
+        let server = TestServer::start(vec![MockResponse::json(200, body)]).await;
+        let client = GammaClient::new(&server.url);
+
+        for url in [
+            "https://polymarket.com/event/rain/will-it-rain?tid=1",
+            "https://polymarket.com/market/will-it-rain/",
+        ] {
+            let market = client.get_market_by_url(url).await.unwrap().unwrap();
+            assert_eq!(market.condition_id, "0xabc");
+        }
+        let requests = server.requests();
+        assert!(requests
+            .iter()
+            .all(|r| r.path == "/markets/slug/will-it-rain"));
+
+        for url in [
+            "https://polymarket.com/event/rain",
+            "will-it-rain",
+            "https://polymarket.com/",
+        ] {
+            assert!(
+                matches!(
+                    client.get_market_by_url(url).await,
+                    Err(Error::InvalidParameter(_))
+                ),
+                "{url}"
+            );
+        }
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -1,11 +1,14 @@
 mod authenticated;
+mod cache;
 mod clob;
 mod data;
 mod gamma;
+mod polymarket;
 mod trading;
 
 pub use authenticated::AuthenticatedClient;
 pub use clob::ClobClient;
 pub use data::DataClient;
 pub use gamma::GammaClient;
+pub use polymarket::{env, PolymarketClient};
 pub use trading::TradingClient;
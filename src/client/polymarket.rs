@@ -0,0 +1,317 @@
+use crate::config::Network;
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::orders::OrderBuilder;
+use crate::types::ApiCreds;
+use alloy_signer_local::PrivateKeySigner;
+use std::str::FromStr;
+
+use super::{ClobClient, DataClient, GammaClient, TradingClient};
+
+/// Environment variables read by [`PolymarketClient::from_env`]
+pub mod env {
+    /// Hex private key of the wallet signing orders (required)
+    pub const PRIVATE_KEY: &str = "POLY_PRIVATE_KEY";
+    /// L2 API key (required)
+    pub const API_KEY: &str = "POLY_API_KEY";
+    /// L2 API secret (required)
+    pub const SECRET: &str = "POLY_SECRET";
+    /// L2 API passphrase (required)
+    pub const PASSPHRASE: &str = "POLY_PASSPHRASE";
+    /// Network to sign orders for, `mainnet` or `amoy` (optional, defaults to mainnet)
+    pub const NETWORK: &str = "POLY_NETWORK";
+    /// Base URL of the CLOB API (optional)
+    pub const CLOB_URL: &str = "POLY_CLOB_URL";
+    /// Base URL of the Gamma API (optional)
+    pub const GAMMA_URL: &str = "POLY_GAMMA_URL";
+    /// Base URL of the Data API (optional)
+    pub const DATA_URL: &str = "POLY_DATA_URL";
+}
+
+/// Clients for the CLOB, Gamma and Data APIs in one place
+///
+/// The public clients are always available; a [`TradingClient`] is attached
+/// with [`with_trading`](Self::with_trading) or built by
+/// [`from_env`](Self::from_env). Each API lives on its own host. [`new`](Self::new) uses the production
+/// hosts ([`CLOB_URL`](crate::config::CLOB_URL), [`GAMMA_API_URL`](crate::config::GAMMA_API_URL)
+/// and [`DATA_API_URL`](crate::config::DATA_API_URL)), and
+/// [`for_network`](Self::for_network) the hosts of a [`Network`];
+/// [`with_base_url`](Self::with_base_url) points all three at one server,
+/// e.g. a local mock.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::PolymarketClient;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = PolymarketClient::new();
+///
+/// let markets = client.gamma().get_markets(None).await?;
+/// let positions = client.data().get_positions("0x...", None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PolymarketClient {
+    clob: ClobClient,
+    gamma: GammaClient,
+    data: DataClient,
+    trading: Option<TradingClient>,
+}
+
+impl PolymarketClient {
+    /// Create clients for the production APIs
+    pub fn new() -> Self {
+        Self::for_network(Network::PolygonMainnet)
+    }
+
+    /// Create clients for the default hosts of `network`
+    pub fn for_network(network: Network) -> Self {
+        Self::with_urls(network.clob_url(), network.gamma_url(), network.data_url())
+    }
+
+    /// Create clients with a custom host for each API
+    ///
+    /// # Arguments
+    /// * `clob_url` - Base URL of the CLOB API
+    /// * `gamma_url` - Base URL of the Gamma API
+    /// * `data_url` - Base URL of the Data API
+    pub fn with_urls(
+        clob_url: impl Into<String>,
+        gamma_url: impl Into<String>,
+        data_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            clob: ClobClient::new(clob_url),
+            gamma: GammaClient::new(gamma_url),
+            data: DataClient::new(data_url),
+            trading: None,
+        }
+    }
+
+    /// Create an authenticated client from environment variables
+    ///
+    /// Reads the wallet key and L2 credentials from [`POLY_PRIVATE_KEY`](env::PRIVATE_KEY),
+    /// [`POLY_API_KEY`](env::API_KEY), [`POLY_SECRET`](env::SECRET) and
+    /// [`POLY_PASSPHRASE`](env::PASSPHRASE), the [`Network`] from
+    /// [`POLY_NETWORK`](env::NETWORK) (mainnet if unset), and optional host
+    /// overrides from [`POLY_CLOB_URL`](env::CLOB_URL),
+    /// [`POLY_GAMMA_URL`](env::GAMMA_URL) and [`POLY_DATA_URL`](env::DATA_URL).
+    /// Orders are signed by the wallet itself (EOA signature type).
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] naming the first missing variable, or if the
+    /// private key or network is invalid. Secret values are never included in errors.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::PolymarketClient;
+    /// use polymarket_rs::types::OpenOrderParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = PolymarketClient::from_env()?;
+    /// let trading = client.trading().expect("configured by from_env");
+    /// let orders = trading.get_open_orders(OpenOrderParams::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// [`from_env`](Self::from_env) with a custom variable lookup
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let required = |name: &str| {
+            var(name)
+                .filter(|value| !value.trim().is_empty())
+                .ok_or_else(|| Error::Config(format!("Missing environment variable {}", name)))
+        };
+
+        let signer =
+            PrivateKeySigner::from_str(required(env::PRIVATE_KEY)?.trim()).map_err(|_| {
+                Error::Config(format!("{} is not a valid private key", env::PRIVATE_KEY))
+            })?;
+        let creds = ApiCreds::new(
+            required(env::API_KEY)?,
+            required(env::SECRET)?,
+            required(env::PASSPHRASE)?,
+        );
+
+        let network = match var(env::NETWORK).filter(|value| !value.trim().is_empty()) {
+            Some(value) => value
+                .trim()
+                .parse::<Network>()
+                .map_err(|_| Error::Config(format!("{} must be mainnet or amoy", env::NETWORK)))?,
+            None => Network::PolygonMainnet,
+        };
+
+        let clob_url = var(env::CLOB_URL).unwrap_or_else(|| network.clob_url().to_string());
+        let gamma_url = var(env::GAMMA_URL).unwrap_or_else(|| network.gamma_url().to_string());
+        let data_url = var(env::DATA_URL).unwrap_or_else(|| network.data_url().to_string());
+
+        let order_builder = OrderBuilder::new(signer.clone(), None, None);
+        let trading = TradingClient::new(
+            clob_url.clone(),
+            signer,
+            network.chain_id(),
+            creds,
+            order_builder,
+        );
+        Ok(Self::with_urls(clob_url, gamma_url, data_url).with_trading(trading))
+    }
+
+    /// Attach an authenticated trading client
+    pub fn with_trading(mut self, trading: TradingClient) -> Self {
+        self.trading = Some(trading);
+        self
+    }
+
+    /// Send requests for all three APIs to the same host
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        Self::with_urls(base_url.clone(), base_url.clone(), base_url)
+    }
+
+    /// Use custom HTTP clients (e.g. built with [`HttpClient::builder`])
+    ///
+    /// The base URL of each client replaces the corresponding host.
+    pub fn with_http_clients(clob: HttpClient, gamma: HttpClient, data: HttpClient) -> Self {
+        Self {
+            clob: ClobClient::new("").with_http_client(clob),
+            gamma: GammaClient::new("").with_http_client(gamma),
+            data: DataClient::new("").with_http_client(data),
+            trading: None,
+        }
+    }
+
+    /// Client for the CLOB market data API
+    pub fn clob(&self) -> &ClobClient {
+        &self.clob
+    }
+
+    /// Client for the Gamma market discovery API
+    pub fn gamma(&self) -> &GammaClient {
+        &self.gamma
+    }
+
+    /// Client for the Data API
+    pub fn data(&self) -> &DataClient {
+        &self.data
+    }
+
+    /// Authenticated trading client, if one is configured
+    pub fn trading(&self) -> Option<&TradingClient> {
+        self.trading.as_ref()
+    }
+}
+
+impl Default for PolymarketClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+
+    #[tokio::test]
+    async fn test_with_base_url_routes_every_api_to_one_host() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, "\"OK\""),
+            MockResponse::json(200, "[]"),
+            MockResponse::json(200, "[]"),
+        ])
+        .await;
+        let client = PolymarketClient::with_base_url(&server.url);
+
+        client.clob().get_ok().await.unwrap();
+        client.gamma().get_tags().await.unwrap();
+        client.data().get_positions("0xabc", None).await.unwrap();
+
+        let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(paths, vec!["/", "/tags", "/positions?user=0xabc"]);
+    }
+
+    const KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: std::collections::HashMap<String, String> = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[tokio::test]
+    async fn test_from_vars_builds_trading_client() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"limit":100,"count":0,"next_cursor":"LTE=","data":[]}"#,
+        )])
+        .await;
+        let client = PolymarketClient::from_vars(vars(&[
+            (env::PRIVATE_KEY, KEY),
+            (env::API_KEY, "key"),
+            (env::SECRET, "c2VjcmV0"),
+            (env::PASSPHRASE, "pass"),
+            (env::CLOB_URL, &server.url),
+        ]))
+        .unwrap();
+
+        let trading = client.trading().unwrap();
+        trading
+            .get_orders(crate::types::OpenOrderParams::new())
+            .await
+            .unwrap();
+        assert_eq!(server.requests()[0].header("POLY_API_KEY"), Some("key"));
+    }
+
+    #[test]
+    fn test_from_vars_selects_network() {
+        let creds = [
+            (env::PRIVATE_KEY, KEY),
+            (env::API_KEY, "key"),
+            (env::SECRET, "c2VjcmV0"),
+            (env::PASSPHRASE, "pass"),
+        ];
+        let client = PolymarketClient::from_vars(vars(&creds)).unwrap();
+        assert_eq!(
+            client.trading().unwrap().chain_id(),
+            Network::PolygonMainnet.chain_id()
+        );
+
+        let amoy = [&creds[..], &[(env::NETWORK, "amoy")]].concat();
+        let client = PolymarketClient::from_vars(vars(&amoy)).unwrap();
+        assert_eq!(
+            client.trading().unwrap().chain_id(),
+            Network::Amoy.chain_id()
+        );
+
+        let invalid = [&creds[..], &[(env::NETWORK, "ropsten")]].concat();
+        let err = PolymarketClient::from_vars(vars(&invalid)).err().unwrap();
+        assert!(err.to_string().contains("POLY_NETWORK"), "{}", err);
+    }
+
+    #[test]
+    fn test_from_vars_names_missing_variable_without_secrets() {
+        let err = PolymarketClient::from_vars(vars(&[
+            (env::PRIVATE_KEY, KEY),
+            (env::API_KEY, "key"),
+            (env::SECRET, "c2VjcmV0"),
+        ]))
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("POLY_PASSPHRASE"), "{}", err);
+
+        let err = PolymarketClient::from_vars(vars(&[(env::PRIVATE_KEY, "0xnot-a-key")]))
+            .err()
+            .unwrap();
+        let message = err.to_string();
+        assert!(message.contains("POLY_PRIVATE_KEY"), "{}", message);
+        assert!(!message.contains("not-a-key"), "{}", message);
+    }
+}
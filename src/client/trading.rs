@@ -1,12 +1,37 @@
-use crate::error::Result;
-use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
-use crate::signing::EthSigner;
+use super::cache::TtlCache;
+use crate::config::{get_contract_config, Network};
+use crate::error::{Error, Result};
+use crate::http::{build_l1_headers, build_l2_headers, Headers, HttpClient};
+use crate::orders::{calculate_market_price, resolve_order_type, OrderBuilder};
+use crate::request::{collect_all_with_retry, PaginationParams};
+use crate::signing::{order_hash, EthSigner, Order};
 use crate::types::{
-    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
-    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    ApiCreds, CancelOrdersResponse, ClobTrade, ClobTradesResponse, CreateOrderOptions,
+    EarningsParams, EarningsResponse, ExtraOrderArgs, Market, MarketOrderArgs, OpenOrder,
+    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderResult,
+    OrderType, PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TokenId,
+    TradeParams, UserEarning,
 };
+use crate::utils::percent_encode;
+use alloy_primitives::{Address, PrimitiveSignature, U256};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
+
+/// Response of an idempotent post, set once the order is accepted
+type SubmittedOrder = Arc<OnceCell<PostOrderResponse>>;
+
+/// Market rules of a token known to the client, checked by
+/// [`TradingClient::simulate_order`]
+#[derive(Debug, Clone, Copy, Default)]
+struct MarketRules {
+    tick_size: Option<Decimal>,
+    min_order_size: Option<Decimal>,
+}
 
 /// Client for trading operations
 ///
@@ -16,11 +41,29 @@ pub struct TradingClient {
     http_client: HttpClient,
     signer: Box<dyn EthSigner>,
     chain_id: u64,
-    api_creds: ApiCreds,
+    api_creds: RwLock<ApiCreds>,
     order_builder: OrderBuilder,
+    /// Accepted orders by idempotency key with the time they were stored, see
+    /// [`post_order_idempotent`](Self::post_order_idempotent)
+    submitted: Mutex<HashMap<String, (SubmittedOrder, Instant)>>,
+    /// How long an accepted order is remembered, see [`with_idempotency_ttl`](Self::with_idempotency_ttl)
+    idempotency_ttl: Duration,
+    /// Tick and minimum sizes by token, see [`remember_market`](Self::remember_market)
+    market_rules: TtlCache<TokenId, MarketRules>,
+    /// Simulate posts instead of sending them, see [`with_dry_run`](Self::with_dry_run)
+    dry_run: bool,
+    /// Re-derive the credentials on a 401, see [`with_credential_refresh`](Self::with_credential_refresh)
+    refresh_credentials: bool,
+    /// Serializes credential refreshes
+    refresh_lock: tokio::sync::Mutex<()>,
+    /// Bumped every time the credentials are replaced
+    creds_generation: AtomicU64,
 }
 
 impl TradingClient {
+    /// Default of [`with_idempotency_ttl`](Self::with_idempotency_ttl)
+    pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(600);
+
     /// Create a new TradingClient
     ///
     /// # Arguments
@@ -40,16 +83,260 @@ impl TradingClient {
             http_client: HttpClient::new(host),
             signer: Box::new(signer),
             chain_id,
+            api_creds: RwLock::new(api_creds),
+            order_builder,
+            submitted: Mutex::new(HashMap::new()),
+            idempotency_ttl: Self::DEFAULT_IDEMPOTENCY_TTL,
+            market_rules: TtlCache::new(None),
+            dry_run: false,
+            refresh_credentials: false,
+            refresh_lock: tokio::sync::Mutex::new(()),
+            creds_generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Create a TradingClient for the default CLOB host and chain ID of `network`
+    ///
+    /// # Arguments
+    /// * `network` - Network orders are signed for
+    /// * `signer` - The Ethereum signer
+    /// * `api_creds` - API credentials for authentication
+    /// * `order_builder` - OrderBuilder instance for creating orders
+    pub fn for_network(
+        network: Network,
+        signer: impl EthSigner + 'static,
+        api_creds: ApiCreds,
+        order_builder: OrderBuilder,
+    ) -> Self {
+        Self::new(
+            network.clob_url(),
+            signer,
+            network.chain_id(),
             api_creds,
             order_builder,
+        )
+    }
+
+    /// Use a custom HTTP client, see [`ClobClient::with_http_client`](crate::client::ClobClient::with_http_client)
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    /// Simulate every order post instead of sending it
+    ///
+    /// In dry-run mode [`post_order`](Self::post_order),
+    /// [`post_orders`](Self::post_orders) and the methods built on them make
+    /// no network call: each order is checked with
+    /// [`simulate_order`](Self::simulate_order) and a simulated response is
+    /// returned. Queries and cancellations are still sent.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// How long [`post_order_idempotent`](Self::post_order_idempotent)
+    /// remembers an accepted order
+    ///
+    /// `ttl` after the accepted post started, the key is forgotten and a call
+    /// with it posts the order again. Expired keys are dropped on the next idempotent post, which
+    /// keeps the memory used bounded by the order rate. Defaults to
+    /// [`DEFAULT_IDEMPOTENCY_TTL`](Self::DEFAULT_IDEMPOTENCY_TTL).
+    pub fn with_idempotency_ttl(mut self, ttl: Duration) -> Self {
+        self.idempotency_ttl = ttl;
+        self
+    }
+
+    /// Record the tick size and minimum order size of both tokens of `market`
+    ///
+    /// [`simulate_order`](Self::simulate_order), and so dry-run posts, check
+    /// orders against them. The tick size passed in the options of
+    /// [`create_order`](Self::create_order) is recorded as well.
+    pub fn remember_market(&self, market: &Market) {
+        for token in &market.tokens {
+            self.market_rules.insert(
+                TokenId::new(token.token_id.clone()),
+                MarketRules {
+                    tick_size: Some(market.minimum_tick_size),
+                    min_order_size: Some(market.minimum_order_size),
+                },
+            );
         }
     }
 
+    /// Record the tick size of `options` for `token_id`
+    fn remember_tick_size(&self, token_id: &str, options: &CreateOrderOptions) {
+        if let Some(tick_size) = options.tick_size {
+            let token_id = TokenId::new(token_id);
+            let rules = self.market_rules.get(&token_id).unwrap_or_default();
+            self.market_rules.insert(
+                token_id,
+                MarketRules {
+                    tick_size: Some(tick_size),
+                    ..rules
+                },
+            );
+        }
+    }
+
+    /// Whether order posts are simulated, see [`with_dry_run`](Self::with_dry_run)
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Refresh the API credentials when an authenticated call is refused
+    ///
+    /// When enabled, a request answered with 401 Unauthorized re-derives the
+    /// API key with the signer (L1 authentication, default nonce), swaps in
+    /// the new credentials and retries the request once. A second 401 is
+    /// returned as-is. Order posts are retried too: the exchange refuses an
+    /// unauthorized order before matching it.
+    ///
+    /// Refreshes are single-flight: when several concurrent requests get a
+    /// 401 for the same credentials, the first one derives the key while the
+    /// others wait for it, then all of them retry with the new credentials.
+    /// A request is only refreshed for if its credentials are still current,
+    /// so a burst of 401s triggers one derivation, not one per request.
+    ///
+    /// Disabled by default, since L2 credentials don't expire.
+    pub fn with_credential_refresh(mut self, enabled: bool) -> Self {
+        self.refresh_credentials = enabled;
+        self
+    }
+
+    /// API credentials currently used to sign requests
+    ///
+    /// These change after a refresh, see
+    /// [`with_credential_refresh`](Self::with_credential_refresh).
+    pub fn api_creds(&self) -> ApiCreds {
+        self.api_creds
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Chain ID orders are signed for
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Correct signed timestamps for the offset of the local clock
+    ///
+    /// L2 signatures include a timestamp, and requests are refused as
+    /// unauthorized when the local clock drifts too far from the server's.
+    /// This measures the offset once with
+    /// [`HttpClient::sync_server_time`] and applies it to every following
+    /// request. Returns the skew in seconds (positive when the local clock is
+    /// behind).
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.http_client.sync_server_time().await
+    }
+
+    /// Clock skew measured by [`sync_clock`](Self::sync_clock), if any
+    pub fn clock_skew(&self) -> Option<i64> {
+        self.http_client.clock_skew()
+    }
+
+    /// L2 headers signed at the server-corrected time
+    fn l2_headers<T>(&self, method: &str, req_path: &str, body: Option<&T>) -> Result<Headers>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        build_l2_headers(
+            self.signer.address(),
+            &self.api_creds.read().unwrap_or_else(|e| e.into_inner()),
+            self.http_client.server_time_secs()?,
+            method,
+            req_path,
+            body,
+        )
+    }
+
+    /// Run an authenticated call, refreshing the credentials once on a 401
+    ///
+    /// `call` must sign its request when invoked so that the retry uses the
+    /// refreshed credentials.
+    async fn with_auth_refresh<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let generation = self.creds_generation.load(Ordering::Acquire);
+        match call().await {
+            Err(Error::Api { status: 401, .. }) if self.refresh_credentials => {
+                self.refresh_api_creds(generation).await?;
+                call().await
+            }
+            result => result,
+        }
+    }
+
+    /// Re-derive the API key unless the credentials of `generation` were already replaced
+    async fn refresh_api_creds(&self, generation: u64) -> Result<()> {
+        let _guard = self.refresh_lock.lock().await;
+        if self.creds_generation.load(Ordering::Acquire) != generation {
+            // Another request refreshed them while this one waited
+            return Ok(());
+        }
+
+        let timestamp = self.http_client.server_time_secs()?;
+        let headers = build_l1_headers(&self.signer, self.chain_id, None, timestamp)?;
+        let creds: ApiCreds = self
+            .http_client
+            .get("/auth/derive-api-key", Some(headers))
+            .await?;
+
+        *self.api_creds.write().unwrap_or_else(|e| e.into_inner()) = creds;
+        self.creds_generation.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Authenticated GET of `request_path`, signed for `base_path` (the path without the query)
+    async fn get_l2<T>(&self, base_path: &str, request_path: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.with_auth_refresh(|| async move {
+            let headers = self.l2_headers::<()>("GET", base_path, None)?;
+            self.http_client.get(request_path, Some(headers)).await
+        })
+        .await
+    }
+
+    /// Authenticated POST of `body`
+    async fn post_l2<T, B>(&self, path: &str, body: &B) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.with_auth_refresh(|| async move {
+            let headers = self.l2_headers("POST", path, Some(body))?;
+            self.http_client.post(path, body, Some(headers)).await
+        })
+        .await
+    }
+
+    /// Authenticated DELETE with a JSON `body`
+    async fn delete_l2<T, B>(&self, path: &str, body: &B) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.with_auth_refresh(|| async move {
+            let headers = self.l2_headers("DELETE", path, Some(body))?;
+            self.http_client
+                .delete_with_body(path, body, Some(headers))
+                .await
+        })
+        .await
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional expiration timestamp for GTD orders, see
+    ///   [`expiration_in`](crate::orders::expiration_in) (defaults to 0 = no expiration)
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     pub fn create_order(
@@ -62,6 +349,7 @@ impl TradingClient {
         let expiration = expiration.unwrap_or(0);
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
+        self.remember_tick_size(&order_args.token_id, &options);
 
         self.order_builder
             .create_order(self.chain_id, order_args, expiration, extras, options)
@@ -83,6 +371,7 @@ impl TradingClient {
     ) -> Result<SignedOrderRequest> {
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
+        self.remember_tick_size(&order_args.token_id, &options);
 
         // Use asks for BUY (taking from sellers), bids for SELL (taking from buyers)
         let book_side = match order_args.side {
@@ -99,31 +388,260 @@ impl TradingClient {
 
     /// Post an order to the exchange
     ///
+    /// The order is typically built and signed with [`create_order`](Self::create_order)
+    /// or [`create_market_order`](Self::create_market_order).
+    ///
     /// # Arguments
     /// * `order` - The signed order to post
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    ///
+    /// # Errors
+    /// Orders the exchange refuses (e.g. below the minimum size or off the
+    /// tick size) are returned as [`Error::OrderRejected`](crate::Error::OrderRejected), or
+    /// [`Error::InsufficientBalance`](crate::Error::InsufficientBalance) when the balance or allowance is too low,
+    /// whether the API reports them with an error status or with `success: false`.
+    ///
+    /// The order type is checked against the order with
+    /// [`resolve_order_type`](crate::orders::resolve_order_type): an order
+    /// signed with an expiration is posted as [`OrderType::Gtd`], and invalid
+    /// combinations are refused with [`Error::InvalidOrder`] before sending.
     pub async fn post_order(
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
-        let owner = self.api_creds.api_key.clone();
-        let post_order = PostOrder::new(order, owner, order_type);
-
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/order",
-            Some(&post_order),
-        )?;
-        self.http_client
-            .post("/order", &post_order, Some(headers))
+        let order_type = resolve_order_type(&order, order_type)?;
+        if self.dry_run {
+            return self.simulate_order(&order, order_type, None);
+        }
+        let order = &order;
+
+        let response: PostOrderResponse = self
+            .with_auth_refresh(|| async move {
+                let owner = self.api_creds().api_key;
+                let post_order = PostOrder::new(order.clone(), owner, order_type);
+                let headers = self.l2_headers("POST", "/order", Some(&post_order))?;
+
+                // Order placement is not idempotent, so never retry it automatically
+                self.http_client
+                    .without_retry()
+                    .post("/order", &post_order, Some(headers))
+                    .await
+            })
+            .await?;
+
+        response.into_result()
+    }
+
+    /// Check an order like the exchange would, without sending it
+    ///
+    /// No network call is made. The checks mirror what a real post would
+    /// report, with the same error variants:
+    /// - the order type against the order, as in [`post_order`](Self::post_order),
+    /// - positive amounts and a price strictly between 0 and 1,
+    /// - the tick size and minimum order size of the token, when the client
+    ///   knows them from [`remember_market`](Self::remember_market) or from
+    ///   the options the order was created with,
+    /// - the signature, which must be from the order's signer for an exchange
+    ///   contract of this client's chain,
+    /// - with `book`, whether a FOK order can be filled completely and a FAK
+    ///   order at all at its price.
+    ///
+    /// Balances and allowances are only known to the exchange and are not
+    /// checked.
+    ///
+    /// On success the response carries the order hash as its ID, like the real
+    /// one, and a `status` of `matched` or `live` depending on whether the
+    /// order crosses `book`, or `simulated` without a book.
+    pub fn simulate_order(
+        &self,
+        order: &SignedOrderRequest,
+        order_type: OrderType,
+        book: Option<&OrderBookSummary>,
+    ) -> Result<PostOrderResponse> {
+        let order_type = resolve_order_type(order, order_type)?;
+        let signed = order.to_order()?;
+        let side = Side::from_u8(signed.side)
+            .ok_or_else(|| Error::InvalidOrder("Invalid side in signed order".to_string()))?;
+
+        // Amounts are in 1e6 token units
+        let amount = |units: U256| i64::try_from(units).map(|units| Decimal::new(units, 6));
+        let (Ok(maker), Ok(taker)) = (amount(signed.makerAmount), amount(signed.takerAmount))
+        else {
+            return Err(Error::InvalidOrder(
+                "Order amounts are out of range".to_string(),
+            ));
+        };
+        if maker.is_zero() || taker.is_zero() {
+            return Err(Error::order_rejected(
+                "invalid order: amounts must be positive".to_string(),
+            ));
+        }
+        // BUY orders pay USDC for shares, SELL orders the other way round
+        let (price, shares) = match side {
+            Side::Buy => (maker / taker, taker),
+            Side::Sell => (taker / maker, maker),
+        };
+        if price <= Decimal::ZERO || price >= Decimal::ONE {
+            return Err(Error::order_rejected(format!(
+                "invalid order: price {} is outside (0, 1)",
+                price.normalize()
+            )));
+        }
+
+        let order_id = self
+            .signed_order_hash(&signed, &order.signature)
+            .ok_or_else(|| Error::order_rejected("invalid signature".to_string()))?;
+
+        let rules = self
+            .market_rules
+            .get(&TokenId::new(order.token_id.clone()))
+            .unwrap_or_default();
+        if let Some(tick_size) = rules.tick_size.filter(|tick| !tick.is_zero()) {
+            // Allow for the rounding of the order amounts
+            if !(price / tick_size).round_dp(2).fract().is_zero() {
+                return Err(Error::order_rejected(format!(
+                    "order {} is invalid. Price ({}) breaks minimum tick size rule: {}",
+                    order_id,
+                    price.normalize(),
+                    tick_size.normalize()
+                )));
+            }
+        }
+        if let Some(min_order_size) = rules.min_order_size {
+            if shares < min_order_size {
+                return Err(Error::order_rejected(format!(
+                    "order {} is invalid. Size ({}) lower than the minimum: {}",
+                    order_id,
+                    shares.normalize(),
+                    min_order_size.normalize()
+                )));
+            }
+        }
+
+        let status = match book {
+            Some(book) => {
+                let levels = match side {
+                    Side::Buy => &book.asks,
+                    Side::Sell => &book.bids,
+                };
+                let available: Decimal = levels
+                    .iter()
+                    .filter(|level| match side {
+                        Side::Buy => level.price <= price,
+                        Side::Sell => level.price >= price,
+                    })
+                    .map(|level| level.size)
+                    .sum();
+                if order_type == OrderType::Fok && available < shares {
+                    return Err(Error::order_rejected(
+                        "order couldn't be fully filled. FOK orders are fully filled or killed."
+                            .to_string(),
+                    ));
+                }
+                if order_type == OrderType::Fak && available.is_zero() {
+                    return Err(Error::order_rejected(
+                        "no orders found to match with FAK order. FAK orders are partially filled or killed if no match is found."
+                            .to_string(),
+                    ));
+                }
+                if book.is_crossing(price, side) {
+                    "matched"
+                } else {
+                    "live"
+                }
+            }
+            None => "simulated",
+        };
+
+        Ok(PostOrderResponse {
+            error_msg: String::new(),
+            order_id,
+            status: status.to_string(),
+            success: true,
+            making_amount: None,
+            taking_amount: None,
+            transactions_hashes: Vec::new(),
+        })
+    }
+
+    /// Hash of `order` for the exchange contract its signature is valid for
+    fn signed_order_hash(&self, order: &Order, signature: &str) -> Option<OrderId> {
+        let signature = alloy_primitives::hex::decode(signature).ok()?;
+        let signature = PrimitiveSignature::try_from(signature.as_slice()).ok()?;
+        [false, true].into_iter().find_map(|neg_risk| {
+            let exchange: Address = get_contract_config(self.chain_id, neg_risk)
+                .ok()?
+                .exchange
+                .parse()
+                .ok()?;
+            let hash = order_hash(order, self.chain_id, exchange);
+            let signer = signature.recover_address_from_prehash(&hash).ok()?;
+            (signer == order.signer).then(|| OrderId::new(hash.to_string()))
+        })
+    }
+
+    /// Post an order at most once per idempotency key
+    ///
+    /// The Polymarket API has no idempotency keys, so deduplication happens in
+    /// this client and only lasts as long as it does:
+    /// - Once an order posted under `key` is accepted, later calls with the same
+    ///   key return the stored response without sending anything.
+    /// - Concurrent calls with the same key wait for the first one to finish.
+    /// - If the post fails, nothing is stored and the next call with the key
+    ///   sends the order again.
+    /// - Keys are remembered for [`with_idempotency_ttl`](Self::with_idempotency_ttl).
+    /// - In dry-run mode the order is simulated every time and nothing is
+    ///   stored, so switching dry run off later doesn't skip the real post.
+    ///
+    /// A transport error such as a timeout doesn't tell whether the exchange
+    /// received the order, so check the open orders before retrying after one.
+    ///
+    /// # Arguments
+    /// * `order` - The signed order to post
+    /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    /// * `key` - Caller-chosen key identifying this logical order
+    pub async fn post_order_idempotent(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        key: impl Into<String>,
+    ) -> Result<PostOrderResponse> {
+        if self.dry_run {
+            return self.post_order(order, order_type).await;
+        }
+
+        let cell = {
+            let mut submitted = self.submitted.lock().unwrap_or_else(|e| e.into_inner());
+            // Keep posts in flight (shared cell) and accepted orders that
+            // haven't expired; failed posts leave an empty cell nobody holds
+            submitted.retain(|_, (cell, stored)| {
+                if cell.initialized() {
+                    stored.elapsed() < self.idempotency_ttl
+                } else {
+                    Arc::strong_count(cell) > 1
+                }
+            });
+            submitted
+                .entry(key.into())
+                .or_insert_with(|| (Arc::default(), Instant::now()))
+                .0
+                .clone()
+        };
+
+        cell.get_or_try_init(|| self.post_order(order, order_type))
             .await
+            .cloned()
     }
 
     /// Post multiple orders to the exchange
     ///
+    /// The orders are sent in a single request. A rejected order doesn't fail
+    /// the batch: each element of the result is the outcome of the order at the
+    /// same index in `orders`, with rejections classified as in
+    /// [`post_order`](Self::post_order). The outer error is only returned if the
+    /// request itself fails.
+    ///
     /// # Arguments
     /// * `orders` - Slice of order arguments with their types
     ///
@@ -136,29 +654,53 @@ impl TradingClient {
     ///     PostOrderArgs::new(order1, OrderType::Gtc),
     ///     PostOrderArgs::new(order2, OrderType::Gtc),
     /// ]).await?;
+    ///
+    /// for result in results {
+    ///     match result {
+    ///         Ok(response) => println!("Placed {}", response.order_id),
+    ///         Err(e) => println!("Rejected: {}", e),
+    ///     }
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
-        let owner = self.api_creds.api_key.clone();
-
-        // Build array of PostOrder structs
-        let post_orders: Vec<PostOrder> = orders
+    pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<OrderResult>> {
+        let order_types: Vec<OrderType> = orders
             .iter()
-            .map(|arg| PostOrder::new(arg.order.clone(), owner.clone(), arg.order_type))
-            .collect();
+            .map(|arg| resolve_order_type(&arg.order, arg.order_type))
+            .collect::<Result<_>>()?;
+        if self.dry_run {
+            return Ok(orders
+                .iter()
+                .map(|arg| self.simulate_order(&arg.order, arg.order_type, None))
+                .collect());
+        }
+        let order_types = &order_types;
 
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/orders",
-            Some(&post_orders),
-        )?;
+        let responses: Vec<PostOrderResponse> = self
+            .with_auth_refresh(|| async move {
+                let owner = self.api_creds().api_key;
+                let post_orders: Vec<PostOrder> = orders
+                    .iter()
+                    .zip(order_types)
+                    .map(|(arg, &order_type)| {
+                        PostOrder::new(arg.order.clone(), owner.clone(), order_type)
+                    })
+                    .collect();
+                let headers = self.l2_headers("POST", "/orders", Some(&post_orders))?;
 
-        self.http_client
-            .post("/orders", &post_orders, Some(headers))
-            .await
+                self.http_client
+                    .without_retry()
+                    .post("/orders", &post_orders, Some(headers))
+                    .await
+            })
+            .await?;
+
+        // The API answers in request order, one entry per order
+        Ok(responses
+            .into_iter()
+            .map(PostOrderResponse::into_result)
+            .collect())
     }
 
     /// Create and post an order in one step
@@ -167,7 +709,8 @@ impl TradingClient {
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional expiration timestamp for GTD orders, see
+    ///   [`expiration_in`](crate::orders::expiration_in) (defaults to 0 = no expiration)
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
@@ -183,60 +726,256 @@ impl TradingClient {
         self.post_order(order, order_type).await
     }
 
-    /// Get open orders (L2 authentication required)
+    /// Create and post a market order in one step
+    ///
+    /// The price is computed from `order_book` like
+    /// [`create_market_order`](Self::create_market_order). Market orders take
+    /// liquidity, so `order_type` must be [`OrderType::Fok`] (fill completely
+    /// or cancel) or [`OrderType::Fak`] (fill what's available, cancel the rest).
+    ///
+    /// # Arguments
+    /// * `order_args` - Market order arguments (token_id, amount, side)
+    /// * `order_book` - The order book to calculate price from
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    /// * `order_type` - FOK or FAK
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] for a resting order type.
+    pub async fn create_and_post_market_order(
+        &self,
+        order_args: &MarketOrderArgs,
+        order_book: &OrderBookSummary,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        if !order_type.is_immediate() {
+            return Err(Error::InvalidOrder(format!(
+                "Market orders must be FOK or FAK, got {:?}",
+                order_type
+            )));
+        }
+        let order = self.create_market_order(order_args, order_book, extras, options)?;
+        self.post_order(order, order_type).await
+    }
+
+    /// Get the first page of open orders (L2 authentication required)
     ///
     /// # Arguments
     /// * `params` - Query parameters to filter orders
     pub async fn get_orders(&self, params: OpenOrderParams) -> Result<OpenOrdersResponse> {
+        self.get_orders_page(&params, None).await
+    }
+
+    /// Get one page of open orders (L2 authentication required)
+    ///
+    /// The CLOB can't filter by side, so `params.side` is applied to the page
+    /// after it is fetched: a page may hold fewer orders than the server's
+    /// page size, or none while later pages still have matches.
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters to filter orders
+    /// * `pagination` - Cursor of the page to fetch (`None` = first page)
+    pub async fn get_orders_page(
+        &self,
+        params: &OpenOrderParams,
+        pagination: Option<PaginationParams>,
+    ) -> Result<OpenOrdersResponse> {
+        if let Some(pagination) = &pagination {
+            pagination.validate()?;
+        }
+
         // IMPORTANT: Sign the base path WITHOUT query parameters
         // Query parameters are added to the URL after signing
         let base_path = "/data/orders";
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let mut query_params: Vec<(&str, String)> = params
+            .to_query_params()
+            .into_iter()
+            .map(|(k, v)| (k, v.clone()))
+            .collect();
+        if let Some(pagination) = &pagination {
+            query_params.extend(pagination.to_query_params());
+        }
+        let request_path = &path_with_query(base_path, &query_params);
+
+        let mut response: OpenOrdersResponse = self
+            .with_auth_refresh(|| async move {
+                let headers = self.l2_headers::<()>("GET", base_path, None)?;
+                self.http_client.get(request_path, Some(headers)).await
+            })
+            .await?;
+        if let Some(side) = params.side {
+            response.data.retain(|order| order.side == side);
+        }
+        Ok(response)
+    }
 
-        self.http_client.get(&request_path, Some(headers)).await
+    /// Get every open order matching `params`, following pagination
+    ///
+    /// Use this to reconcile local state with the exchange, e.g. before
+    /// requoting or after a reconnect. A failed page is retried with the
+    /// client's [`RetryPolicy`](crate::RetryPolicy).
+    ///
+    /// Order ID, market and asset are filtered by the CLOB. The side is
+    /// filtered client-side, so every page matching the other filters is
+    /// still fetched.
+    ///
+    /// # Arguments
+    /// * `params` - Filters by order ID, market, asset and side
+    pub async fn get_open_orders(&self, params: OpenOrderParams) -> Result<Vec<OpenOrder>> {
+        let retry = self.http_client.retry_policy().clone();
+        collect_all_with_retry(
+            |page| self.get_orders_page(&params, Some(page)),
+            retry,
+            None,
+        )
+        .await
     }
 
     /// Get a specific order by ID
     pub async fn get_order(&self, order_id: &OrderId) -> Result<OpenOrder> {
-        let path = format!("/data/order/{}", order_id.as_str());
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", &path, None)?;
-        self.http_client.get(&path, Some(headers)).await
+        let path = &format!("/data/order/{}", order_id.as_str());
+        self.with_auth_refresh(|| async move {
+            let headers = self.l2_headers::<()>("GET", path, None)?;
+            self.http_client.get(path, Some(headers)).await
+        })
+        .await
+    }
+
+    /// Poll an order until it reaches a terminal state
+    ///
+    /// Calls [`get_order`](Self::get_order) every `poll_interval` until
+    /// [`OpenOrder::is_terminal`] holds, and returns the final order. When the
+    /// API rate-limits a poll, waits for its `Retry-After` (or twice the
+    /// current interval) before the next one.
+    ///
+    /// # Arguments
+    /// * `order_id` - The order to wait for
+    /// * `timeout` - How long to wait in total
+    /// * `poll_interval` - Delay between polls
+    ///
+    /// # Errors
+    /// Returns [`Error::Timeout`](crate::Error::Timeout) if the order is still
+    /// open after `timeout`, and any error other than rate limiting from
+    /// `get_order` right away.
+    pub async fn wait_for_order(
+        &self,
+        order_id: &OrderId,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<OpenOrder> {
+        let poll = async {
+            let mut interval = poll_interval;
+            loop {
+                match self.get_order(order_id).await {
+                    Ok(order) if order.is_terminal() => return Ok(order),
+                    Ok(_) => interval = poll_interval,
+                    Err(Error::RateLimited { retry_after }) => {
+                        interval = retry_after.unwrap_or(interval * 2);
+                    }
+                    Err(e) => return Err(e),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, poll).await.map_err(|_| {
+            Error::Timeout(format!(
+                "Order {} did not reach a terminal state within {:?}",
+                order_id.as_str(),
+                timeout
+            ))
+        })?
     }
 
     /// Cancel a specific order
     ///
+    /// Check [`CancelOrdersResponse::is_canceled`] for the outcome: an order that
+    /// was already filled or canceled is reported in `not_canceled` with the reason.
+    ///
     /// # Arguments
     /// * `order_id` - The ID of the order to cancel
-    pub async fn cancel(&self, order_id: &OrderId) -> Result<CancelOrdersResponse> {
+    pub async fn cancel_order(&self, order_id: &OrderId) -> Result<CancelOrdersResponse> {
         let body = serde_json::json!({ "orderID": order_id.as_str() });
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/order",
-            Some(&body),
-        )?;
-        self.http_client
-            .delete_with_body("/order", &body, Some(headers))
-            .await
+        self.delete_l2("/order", &body).await
+    }
+
+    /// Cancel a specific order
+    #[deprecated(note = "use cancel_order")]
+    pub async fn cancel(&self, order_id: &OrderId) -> Result<CancelOrdersResponse> {
+        self.cancel_order(order_id).await
+    }
+
+    /// Reprice an open order: cancel it and post a replacement
+    ///
+    /// The replacement keeps the token, side and order type of `order_id`
+    /// (and the expiration of a GTD order) with `new_price` and `new_size`.
+    /// It is signed before anything is sent, so an invalid price or size
+    /// leaves the original order in place. Returns the ID of the new order.
+    ///
+    /// The exchange has no atomic replace: the cancel and the post are two
+    /// requests, so the account has no quote in between and the original
+    /// order can still match until the cancel lands. The order is only
+    /// replaced if the cancel succeeds.
+    ///
+    /// In dry-run mode the original order is looked up but not canceled, and
+    /// the replacement is simulated like any post, so the live quote stays.
+    ///
+    /// # Arguments
+    /// * `order_id` - The order to replace
+    /// * `new_price` - Price of the replacement
+    /// * `new_size` - Size of the replacement
+    /// * `extras` - Fee rate, nonce and taker of the replacement; the API
+    ///   doesn't return those of the original order, so pass the ones it was
+    ///   created with (defaults to ExtraOrderArgs::default())
+    /// * `options` - Order options of the market (tick_size, neg_risk must be provided)
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] without posting if the order could not
+    /// be canceled (e.g. it was filled in the meantime), and
+    /// [`Error::ReplaceFailed`] if it was canceled but the replacement failed,
+    /// in which case the account has no order left for this quote.
+    pub async fn replace_order(
+        &self,
+        order_id: &OrderId,
+        new_price: Decimal,
+        new_size: Decimal,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<OrderId> {
+        let existing = self.get_order(order_id).await?;
+        let args = OrderArgs::new(existing.asset_id, new_price, new_size, existing.side);
+        let expiration = match existing.order_type {
+            OrderType::Gtd => Some(existing.expiration),
+            _ => None,
+        };
+        let replacement = self.create_order(&args, expiration, extras, options)?;
+        if self.dry_run {
+            let simulated = self.simulate_order(&replacement, existing.order_type, None)?;
+            return Ok(simulated.order_id);
+        }
+
+        let canceled = self.cancel_order(order_id).await?;
+        if !canceled.is_canceled(order_id) {
+            return Err(Error::InvalidOrder(format!(
+                "Order {} could not be canceled: {}",
+                order_id.as_str(),
+                canceled
+                    .not_canceled_reason(order_id)
+                    .unwrap_or("not reported as canceled")
+            )));
+        }
+
+        match self.post_order(replacement, existing.order_type).await {
+            Ok(response) => Ok(response.order_id),
+            Err(e) => Err(Error::ReplaceFailed {
+                canceled: order_id.clone(),
+                source: Box::new(e),
+            }),
+        }
     }
 
     /// Cancel multiple orders
@@ -246,31 +985,21 @@ impl TradingClient {
     pub async fn cancel_orders(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/orders",
-            Some(&body),
-        )?;
-        self.http_client
-            .delete_with_body("/orders", &body, Some(headers))
-            .await
+        self.delete_l2("/orders", &body).await
     }
 
     /// Cancel all orders
-    pub async fn cancel_all(&self) -> Result<CancelOrdersResponse> {
+    ///
+    /// # Arguments
+    /// * `market` - Only cancel orders in this market (condition ID); `None` cancels
+    ///   every open order
+    pub async fn cancel_all(&self, market: Option<&str>) -> Result<CancelOrdersResponse> {
+        if market.is_some() {
+            return self.cancel_market_orders(market, None).await;
+        }
+
         let body = serde_json::json!({});
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/cancel-all",
-            Some(&body),
-        )?;
-        self.http_client
-            .delete_with_body("/cancel-all", &body, Some(headers))
-            .await
+        self.delete_l2("/cancel-all", &body).await
     }
 
     /// Cancel all orders for a specific market and/or asset
@@ -289,73 +1018,1027 @@ impl TradingClient {
             "asset_id": asset_id.unwrap_or("")
         });
 
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "DELETE",
-            "/cancel-market-orders",
-            Some(&body),
-        )?;
-        self.http_client
-            .delete_with_body("/cancel-market-orders", &body, Some(headers))
-            .await
+        self.delete_l2("/cancel-market-orders", &body).await
+    }
+
+    /// Cancel all orders for a single token (asset)
+    ///
+    /// Useful to pull the quotes on one outcome of a market while keeping the
+    /// others. Succeeds with an empty `canceled` list when there were no open
+    /// orders; use [`cancel_all`](Self::cancel_all) to cancel a whole market.
+    ///
+    /// # Arguments
+    /// * `asset_id` - The token ID to cancel orders for
+    pub async fn cancel_asset_orders(&self, asset_id: &str) -> Result<CancelOrdersResponse> {
+        self.cancel_market_orders(None, Some(asset_id)).await
     }
 
-    /// Get trade history (L2 authentication required)
+    /// Get the full trade history of the authenticated user (L2 authentication required)
+    ///
+    /// Follows pagination until the last page, retrying a failed page with the
+    /// client's [`RetryPolicy`](crate::RetryPolicy). For public trades of any user,
+    /// use [`DataClient::get_trades`](crate::client::DataClient::get_trades).
     ///
     /// # Arguments
     /// * `params` - Query parameters to filter trades
-    pub async fn get_trades(&self, params: TradeParams) -> Result<serde_json::Value> {
+    pub async fn get_trades(&self, params: TradeParams) -> Result<Vec<ClobTrade>> {
+        let retry = self.http_client.retry_policy().clone();
+        collect_all_with_retry(
+            |page| self.get_trades_page(&params, Some(page)),
+            retry,
+            None,
+        )
+        .await
+    }
+
+    /// Get one page of the trade history (L2 authentication required)
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters to filter trades
+    /// * `pagination` - Cursor of the page to fetch (`None` = first page)
+    pub async fn get_trades_page(
+        &self,
+        params: &TradeParams,
+        pagination: Option<PaginationParams>,
+    ) -> Result<ClobTradesResponse> {
+        if let Some(pagination) = &pagination {
+            pagination.validate()?;
+        }
+
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/data/trades";
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
-        // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+        let mut query_params = params.to_query_params();
+        if let Some(pagination) = &pagination {
+            query_params.extend(pagination.to_query_params());
+        }
+        let request_path = path_with_query(base_path, &query_params);
+
+        self.get_l2(base_path, &request_path).await
+    }
+
+    /// Get the liquidity rewards earned on one day (L2 authentication required)
+    ///
+    /// Earnings are those of the account the API credentials belong to, one
+    /// entry per market and reward asset. Fetches every page.
+    ///
+    /// # Arguments
+    /// * `params` - Day to query and signature type of the maker wallet
+    pub async fn get_earnings(&self, params: EarningsParams) -> Result<Vec<UserEarning>> {
+        let retry = self.http_client.retry_policy().clone();
+        collect_all_with_retry(
+            |page| self.get_earnings_page(&params, Some(page)),
+            retry,
+            None,
+        )
+        .await
+    }
+
+    /// Get one page of earnings (L2 authentication required)
+    ///
+    /// # Arguments
+    /// * `params` - Day to query and signature type of the maker wallet
+    /// * `pagination` - Cursor of the page to fetch (`None` = first page)
+    pub async fn get_earnings_page(
+        &self,
+        params: &EarningsParams,
+        pagination: Option<PaginationParams>,
+    ) -> Result<EarningsResponse> {
+        if let Some(pagination) = &pagination {
+            pagination.validate()?;
+        }
+
+        // IMPORTANT: Sign the base path WITHOUT query parameters
+        let base_path = "/rewards/user";
 
-        self.http_client.get(&request_path, Some(headers)).await
+        let mut query_params = params.to_query_params();
+        if let Some(pagination) = &pagination {
+            query_params.extend(pagination.to_query_params());
+        }
+        let request_path = path_with_query(base_path, &query_params);
+
+        self.get_l2(base_path, &request_path).await
     }
 
     /// Check if an order is scoring
     pub async fn is_order_scoring(&self, order_id: &OrderId) -> Result<serde_json::Value> {
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/order-scoring";
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
         // Build the full request path WITH query parameters
-        let request_path = format!("{}?id={}", base_path, order_id.as_str());
+        let request_path = path_with_query(base_path, &[("id", order_id.as_str().to_string())]);
 
-        self.http_client.get(&request_path, Some(headers)).await
+        self.get_l2(base_path, &request_path).await
     }
 
     /// Check if multiple orders are scoring
     pub async fn are_orders_scoring(&self, order_ids: &[OrderId]) -> Result<serde_json::Value> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
-        let headers = create_l2_headers(
-            &self.signer,
-            &self.api_creds,
-            "POST",
-            "/orders-scoring",
-            Some(&body),
-        )?;
-        self.http_client
-            .post("/orders-scoring", &body, Some(headers))
+        self.post_l2("/orders-scoring", &body).await
+    }
+
+    /// Check which of `order_ids` are scoring for liquidity rewards
+    ///
+    /// Sends all IDs in one `/orders-scoring` request. The map has an entry
+    /// for every requested ID the server reported on; an ID it didn't report,
+    /// such as one that doesn't exist or belongs to another account, is left
+    /// out rather than reported as not scoring. Entries for IDs that weren't
+    /// requested are dropped.
+    pub async fn get_order_scoring(&self, order_ids: &[OrderId]) -> Result<HashMap<OrderId, bool>> {
+        if order_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
+        let mut scoring: HashMap<OrderId, bool> = self.post_l2("/orders-scoring", &ids).await?;
+        scoring.retain(|id, _| order_ids.contains(id));
+        Ok(scoring)
+    }
+}
+
+/// Append `query_params` to `base_path`; L2 requests sign the path without them
+///
+/// Keys and values are percent-encoded, like
+/// [`HttpClient::get_with_query`] does for unsigned requests.
+fn path_with_query(base_path: &str, query_params: &[(&str, String)]) -> String {
+    if query_params.is_empty() {
+        return base_path.to_string();
+    }
+    let query = query_params
+        .iter()
+        .map(|(k, v)| format!("{}={}", percent_encode(k), percent_encode(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{}", base_path, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    fn creds() -> ApiCreds {
+        ApiCreds::new(
+            "key".to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string(),
+            "pass".to_string(),
+        )
+    }
+
+    fn client(url: &str) -> TradingClient {
+        let signer = PrivateKeySigner::random();
+        let order_builder = OrderBuilder::new(signer.clone(), None, None);
+        TradingClient::new(url, signer, 137, creds(), order_builder)
+    }
+
+    fn signed_order(client: &TradingClient) -> SignedOrderRequest {
+        let args = OrderArgs::new("1234", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        client.create_order(&args, None, None, options).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_order_signs_and_posts() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"live"}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let order = signed_order(&client);
+        let response = client.post_order(order, OrderType::Gtc).await.unwrap();
+        assert_eq!(response.order_id.as_str(), "0xabc");
+        assert_eq!(response.status, "live");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/order");
+        assert_eq!(request.header("POLY_API_KEY"), Some("key"));
+        assert!(request.header("POLY_SIGNATURE").is_some());
+
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["owner"], "key");
+        assert_eq!(body["orderType"], "GTC");
+        assert_eq!(body["order"]["tokenId"], "1234");
+        assert_eq!(body["order"]["side"], "BUY");
+        assert!(body["order"]["signature"]
+            .as_str()
+            .unwrap()
+            .starts_with("0x"));
+    }
+
+    #[tokio::test]
+    async fn test_post_order_with_expiration_is_gtd() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"live"}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let expiration = crate::orders::expiration_in(Duration::from_secs(3600)).unwrap();
+        let args = OrderArgs::new("1234", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let order = client
+            .create_order(&args, Some(expiration), None, options)
+            .unwrap();
+
+        assert!(matches!(
+            client.post_order(order.clone(), OrderType::Fok).await,
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            client
+                .post_order(signed_order(&client), OrderType::Gtd)
+                .await,
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(server.requests().is_empty());
+
+        client.post_order(order, OrderType::Gtc).await.unwrap();
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(body["orderType"], "GTD");
+        assert_eq!(body["order"]["expiration"], expiration.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_create_and_post_market_order_fok() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"matched"}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+        let book: OrderBookSummary = serde_json::from_str(
+            r#"{"market":"0xm","asset_id":"1234","timestamp":"1700000000","hash":"h",
+                "bids":[{"price":"0.48","size":"100"}],"asks":[{"price":"0.5","size":"100"}]}"#,
+        )
+        .unwrap();
+        let args = MarketOrderArgs::new("1234", dec!(10), Side::Buy);
+        let options = || {
+            CreateOrderOptions::new()
+                .tick_size(dec!(0.01))
+                .neg_risk(false)
+        };
+
+        let err = client
+            .create_and_post_market_order(&args, &book, None, options(), OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidOrder(_)));
+        assert!(server.requests().is_empty());
+
+        client
+            .create_and_post_market_order(&args, &book, None, options(), OrderType::Fok)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        assert_eq!(body["orderType"], "FOK");
+        // $10 at 0.50 buys 20 shares
+        assert_eq!(body["order"]["makerAmount"], "10000000");
+        assert_eq!(body["order"]["takerAmount"], "20000000");
+    }
+
+    #[tokio::test]
+    async fn test_sync_clock_corrects_signed_timestamp() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, "1000"),
+            MockResponse::json(200, r#"{"canceled":[],"not_canceled":{}}"#),
+        ])
+        .await;
+        let client = client(&server.url);
+        assert_eq!(client.clock_skew(), None);
+
+        client.sync_clock().await.unwrap();
+        assert!(client.clock_skew().unwrap() < 0);
+        client.cancel_all(None).await.unwrap();
+
+        let timestamp: u64 = server.requests()[1]
+            .header("POLY_TIMESTAMP")
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(timestamp.abs_diff(1000) <= 1, "timestamp {}", timestamp);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_simulates_without_network() {
+        let server = TestServer::start(vec![]).await;
+        let client = client(&server.url).with_dry_run(true);
+        assert!(client.is_dry_run());
+
+        let response = client
+            .post_order(signed_order(&client), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(response.success);
+        assert_eq!(response.status, "simulated");
+        assert!(response.order_id.as_str().starts_with("0x"));
+        assert_eq!(response.order_id.as_str().len(), 66);
+
+        // A tampered order no longer matches its signature
+        let mut tampered = signed_order(&client);
+        tampered.maker_amount = "6000000".to_string();
+        let err = client
+            .post_order(tampered, OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::OrderRejected { .. }), "{:?}", err);
+
+        let results = client
+            .post_orders(&[
+                PostOrderArgs::new(signed_order(&client), OrderType::Gtc),
+                PostOrderArgs::new(signed_order(&client), OrderType::Gtc),
+            ])
+            .await
+            .unwrap();
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_simulate_order_against_book() {
+        let client = client("http://unused");
+        // BUY 10 shares at 0.50
+        let order = signed_order(&client);
+        let book = |asks: &str| -> OrderBookSummary {
+            serde_json::from_str(&format!(
+                r#"{{"market":"0xm","asset_id":"1234","timestamp":"0","hash":"h","bids":[],"asks":[{}]}}"#,
+                asks
+            ))
+            .unwrap()
+        };
+
+        let deep = book(r#"{"price":"0.5","size":"100"}"#);
+        let thin = book(r#"{"price":"0.5","size":"4"},{"price":"0.6","size":"100"}"#);
+        let away = book(r#"{"price":"0.6","size":"100"}"#);
+
+        let simulate = |order_type, book| client.simulate_order(&order, order_type, Some(book));
+        assert_eq!(simulate(OrderType::Fok, &deep).unwrap().status, "matched");
+        assert_eq!(simulate(OrderType::Fak, &thin).unwrap().status, "matched");
+        assert_eq!(simulate(OrderType::Gtc, &away).unwrap().status, "live");
+        assert!(matches!(
+            simulate(OrderType::Fok, &thin),
+            Err(Error::OrderRejected { .. })
+        ));
+        assert!(matches!(
+            simulate(OrderType::Fak, &away),
+            Err(Error::OrderRejected { .. })
+        ));
+    }
+
+    fn market(tick_size: &str, min_order_size: &str) -> Market {
+        serde_json::from_str(&format!(
+            r#"{{"condition_id":"0xm","tokens":[{{"token_id":"1234","outcome":"Yes"}},{{"token_id":"5678","outcome":"No"}}],
+                "rewards":{{"rates":null,"min_size":0,"max_spread":0}},"active":true,"closed":false,
+                "archived":false,"accepting_orders":true,"question_id":"0xq","question":"?",
+                "minimum_order_size":{},"minimum_tick_size":{}}}"#,
+            min_order_size, tick_size
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_simulate_order_rejects_off_tick_price() {
+        let client = client("http://unused").with_dry_run(true);
+        // On the tick of the options, but not on the market's tick
+        let args = OrderArgs::new("1234", dec!(0.505), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.001))
+            .neg_risk(false);
+        let order = client.create_order(&args, None, None, options).unwrap();
+        assert!(client.simulate_order(&order, OrderType::Gtc, None).is_ok());
+
+        client.remember_market(&market("0.01", "5"));
+        let err = client
+            .simulate_order(&order, OrderType::Gtc, None)
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::OrderRejected { reason }
+                if reason.ends_with("Price (0.505) breaks minimum tick size rule: 0.01")),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_order_rejects_size_below_minimum() {
+        let client = client("http://unused").with_dry_run(true);
+        client.remember_market(&market("0.01", "15"));
+
+        let err = client
+            .post_order(signed_order(&client), OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::OrderRejected { reason }
+                if reason.ends_with("Size (10) lower than the minimum: 15")),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_order_rejected() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"success":false,"errorMsg":"order is invalid. Size lower than the minimum","orderID":"","status":""}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let err = client
+            .post_order(signed_order(&client), OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::OrderRejected { reason } if reason.contains("minimum")));
+    }
+
+    #[tokio::test]
+    async fn test_post_orders_reports_each_order() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"[
+                {"success":true,"errorMsg":"","orderID":"0x1","status":"live"},
+                {"success":false,"errorMsg":"not enough balance / allowance","orderID":""},
+                {"success":true,"errorMsg":"","orderID":"0x3","status":"matched"}
+            ]"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let orders: Vec<PostOrderArgs> = (0..3)
+            .map(|_| PostOrderArgs::new(signed_order(&client), OrderType::Gtc))
+            .collect();
+        let results = client.post_orders(&orders).await.unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().order_id.as_str(), "0x1");
+        assert!(matches!(results[1], Err(Error::InsufficientBalance(_))));
+        assert_eq!(results[2].as_ref().unwrap().status, "matched");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/orders");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body.as_array().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_order_idempotent_dedups_by_key() {
+        let accepted = r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"live"}"#;
+        let server = TestServer::start(vec![
+            MockResponse::json(500, r#"{"error":"boom"}"#),
+            MockResponse::json(200, accepted),
+            MockResponse::json(200, accepted),
+        ])
+        .await;
+        let client = client(&server.url);
+
+        // A failed post leaves the key free for a retry
+        let order = signed_order(&client);
+        let result = client
+            .post_order_idempotent(order.clone(), OrderType::Gtc, "order-1")
+            .await;
+        assert!(result.is_err());
+
+        for _ in 0..2 {
+            let response = client
+                .post_order_idempotent(order.clone(), OrderType::Gtc, "order-1")
+                .await
+                .unwrap();
+            assert_eq!(response.order_id.as_str(), "0xabc");
+        }
+        assert_eq!(server.requests().len(), 2);
+
+        client
+            .post_order_idempotent(order, OrderType::Gtc, "order-2")
+            .await
+            .unwrap();
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_order_idempotent_forgets_expired_keys() {
+        let accepted = r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"live"}"#;
+        let server = TestServer::start(vec![MockResponse::json(200, accepted)]).await;
+        let client = client(&server.url).with_idempotency_ttl(Duration::from_millis(50));
+        let order = signed_order(&client);
+
+        for key in ["order-1", "order-2", "order-1"] {
+            client
+                .post_order_idempotent(order.clone(), OrderType::Gtc, key)
+                .await
+                .unwrap();
+        }
+        assert_eq!(server.requests().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        client
+            .post_order_idempotent(order, OrderType::Gtc, "order-1")
+            .await
+            .unwrap();
+        assert_eq!(server.requests().len(), 3);
+        // The expired "order-2" was dropped along the way
+        assert_eq!(client.submitted.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_post_order_idempotent_does_not_store_dry_runs() {
+        let server = TestServer::start(vec![MockResponse::json(500, "{}")]).await;
+        let client = client(&server.url).with_dry_run(true);
+        let order = signed_order(&client);
+
+        for _ in 0..2 {
+            let response = client
+                .post_order_idempotent(order.clone(), OrderType::Gtc, "order-1")
+                .await
+                .unwrap();
+            assert!(response.success);
+        }
+        assert!(server.requests().is_empty());
+        assert!(client.submitted.lock().unwrap().is_empty());
+    }
+
+    fn order_json(status: &str, size_matched: &str) -> String {
+        format!(
+            r#"{{"id":"0x1","associate_trades":[],"status":"{}","market":"0xm","original_size":"10","outcome":"Yes","maker_address":"0xa","owner":"key","price":"0.5","side":"BUY","size_matched":"{}","asset_id":"1","expiration":"0","order_type":"GTC","created_at":"1700000000"}}"#,
+            status, size_matched
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_order_polls_until_terminal() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, &order_json("LIVE", "0")),
+            MockResponse::json(429, r#"{"error":"rate limited"}"#).header("retry-after", "1"),
+            MockResponse::json(200, &order_json("LIVE", "4")),
+            MockResponse::json(200, &order_json("MATCHED", "10")),
+        ])
+        .await;
+        // Without retries the 429 reaches wait_for_order instead of the HTTP client
+        let http_client = HttpClient::builder(&server.url).no_retry().build().unwrap();
+        let client = client(&server.url).with_http_client(http_client);
+
+        let started = std::time::Instant::now();
+        let order = client
+            .wait_for_order(
+                &OrderId::new("0x1"),
+                Duration::from_secs(5),
+                Duration::from_millis(1),
+            )
+            .await
+            .unwrap();
+        assert!(order.is_terminal());
+        assert_eq!(order.size_matched, dec!(10));
+        assert_eq!(server.requests().len(), 4);
+        assert_eq!(server.requests()[0].path, "/data/order/0x1");
+        // The poll after the 429 waited for Retry-After, not the 1ms interval
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_order_times_out() {
+        // The last mock response is served for every later request
+        let server =
+            TestServer::start(vec![MockResponse::json(200, &order_json("LIVE", "0"))]).await;
+        let client = client(&server.url);
+
+        let result = client
+            .wait_for_order(
+                &OrderId::new("0x1"),
+                Duration::from_millis(50),
+                Duration::from_millis(10),
+            )
+            .await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_typed_result() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"canceled":["0x1"],"not_canceled":{"0x2":"order can't be found - already canceled or matched"}}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let ids = [OrderId::new("0x1"), OrderId::new("0x2")];
+        let response = client.cancel_orders(&ids).await.unwrap();
+        assert!(response.is_canceled(&ids[0]));
+        assert!(!response.is_canceled(&ids[1]));
+        assert!(response
+            .not_canceled_reason(&ids[1])
+            .unwrap()
+            .contains("matched"));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/orders");
+        assert_eq!(request.body, r#"["0x1","0x2"]"#);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn test_deprecated_cancel_delegates_to_cancel_order() {
+        let server =
+            TestServer::start(vec![MockResponse::json(200, r#"{"canceled":["0x1"]}"#)]).await;
+        let client = client(&server.url);
+
+        let response = client.cancel(&OrderId::new("0x1")).await.unwrap();
+        assert!(response.is_canceled(&OrderId::new("0x1")));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "DELETE");
+        assert_eq!(request.path, "/order");
+        assert_eq!(request.body, r#"{"orderID":"0x1"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_cancels_then_posts() {
+        let server = TestServer::start(vec![
+            MockResponse::json(200, &order_json("LIVE", "0")),
+            MockResponse::json(200, r#"{"canceled":["0x1"],"not_canceled":{}}"#),
+            MockResponse::json(
+                200,
+                r#"{"success":true,"errorMsg":"","orderID":"0x2","status":"live"}"#,
+            ),
+        ])
+        .await;
+        let client = client(&server.url);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let extras = ExtraOrderArgs::new().fee_rate_bps(100);
+
+        let new_id = client
+            .replace_order(
+                &OrderId::new("0x1"),
+                dec!(0.52),
+                dec!(20),
+                Some(&extras),
+                options.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(new_id.as_str(), "0x2");
+
+        let requests = server.requests();
+        let methods: Vec<_> = requests.iter().map(|r| r.method.as_str()).collect();
+        assert_eq!(methods, vec!["GET", "DELETE", "POST"]);
+        let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+        assert_eq!(body["order"]["tokenId"], "1");
+        assert_eq!(body["order"]["side"], "BUY");
+        // 20 shares at 0.52 cost 10.4 USDC
+        assert_eq!(body["order"]["makerAmount"], "10400000");
+        assert_eq!(body["order"]["feeRateBps"], "100");
+
+        // A dry run leaves the original order alone
+        let server =
+            TestServer::start(vec![MockResponse::json(200, &order_json("LIVE", "0"))]).await;
+        self::client(&server.url)
+            .with_dry_run(true)
+            .replace_order(&OrderId::new("0x1"), dec!(0.52), dec!(20), None, options)
+            .await
+            .unwrap();
+        let methods: Vec<_> = server.requests().into_iter().map(|r| r.method).collect();
+        assert_eq!(methods, vec!["GET"]);
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_reports_failed_post_after_cancel() {
+        let options = || {
+            CreateOrderOptions::new()
+                .tick_size(dec!(0.01))
+                .neg_risk(false)
+        };
+
+        let server = TestServer::start(vec![
+            MockResponse::json(200, &order_json("LIVE", "0")),
+            MockResponse::json(200, r#"{"canceled":["0x1"],"not_canceled":{}}"#),
+            MockResponse::json(
+                200,
+                r#"{"success":false,"errorMsg":"not enough balance / allowance","orderID":""}"#,
+            ),
+        ])
+        .await;
+        let result = client(&server.url)
+            .replace_order(&OrderId::new("0x1"), dec!(0.52), dec!(20), None, options())
+            .await;
+        match result {
+            Err(Error::ReplaceFailed { canceled, source }) => {
+                assert_eq!(canceled.as_str(), "0x1");
+                assert!(matches!(*source, Error::InsufficientBalance(_)));
+            }
+            other => panic!("expected ReplaceFailed, got {:?}", other),
+        }
+
+        // An order that is already gone is not replaced
+        let server = TestServer::start(vec![
+            MockResponse::json(200, &order_json("LIVE", "0")),
+            MockResponse::json(
+                200,
+                r#"{"canceled":[],"not_canceled":{"0x1":"order can't be found - already canceled or matched"}}"#,
+            ),
+        ])
+        .await;
+        let result = client(&server.url)
+            .replace_order(&OrderId::new("0x1"), dec!(0.52), dec!(20), None, options())
+            .await;
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_scoring() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"0x1":true,"0x2":false,"0x9":true}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let ids = [
+            OrderId::new("0x1"),
+            OrderId::new("0x2"),
+            OrderId::new("0x3"),
+        ];
+        let scoring = client.get_order_scoring(&ids).await.unwrap();
+        assert_eq!(scoring.len(), 2);
+        assert!(scoring[&ids[0]]);
+        assert!(!scoring[&ids[1]]);
+        assert!(!scoring.contains_key(&ids[2]));
+
+        let request = &server.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/orders-scoring");
+        assert_eq!(request.body, r#"["0x1","0x2","0x3"]"#);
+
+        assert!(client.get_order_scoring(&[]).await.unwrap().is_empty());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    /// Refuses the initial API key and counts key derivations
+    #[derive(Default)]
+    struct RotatingKey {
+        derivations: std::sync::atomic::AtomicUsize,
+    }
+
+    impl crate::Transport for Arc<RotatingKey> {
+        fn execute(&self, request: reqwest::Request) -> crate::TransportFuture<'_> {
+            Box::pin(async move {
+                // Let concurrent requests interleave
+                tokio::task::yield_now().await;
+                let (status, body) = if request.url().path() == "/auth/derive-api-key" {
+                    self.derivations.fetch_add(1, Ordering::SeqCst);
+                    let creds = r#"{"apiKey":"new-key","secret":"AAAA","passphrase":"p"}"#;
+                    (200, creds.to_string())
+                } else if request.headers()["POLY_API_KEY"] == "new-key" {
+                    (200, order_json("LIVE", "0"))
+                } else {
+                    (
+                        401,
+                        r#"{"error":"Unauthorized/Invalid api key"}"#.to_string(),
+                    )
+                };
+                Ok(crate::TransportResponse::new(
+                    reqwest::StatusCode::from_u16(status).unwrap(),
+                    body,
+                ))
+            })
+        }
+    }
+
+    fn rotating_client(transport: &Arc<RotatingKey>) -> TradingClient {
+        let http_client = HttpClient::builder("http://mock")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        client("http://mock").with_http_client(http_client)
+    }
+
+    #[tokio::test]
+    async fn test_credential_refresh_is_single_flight() {
+        let transport = Arc::new(RotatingKey::default());
+        let client = rotating_client(&transport).with_credential_refresh(true);
+
+        let id = OrderId::new("0x1");
+        let (first, second) = tokio::join!(client.get_order(&id), client.get_order(&id));
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(transport.derivations.load(Ordering::SeqCst), 1);
+        assert_eq!(client.api_creds().api_key, "new-key");
+    }
+
+    #[tokio::test]
+    async fn test_credential_refresh_is_opt_in() {
+        let transport = Arc::new(RotatingKey::default());
+        let client = rotating_client(&transport);
+
+        let result = client.get_order(&OrderId::new("0x1")).await;
+        assert!(matches!(result, Err(Error::Api { status: 401, .. })));
+        assert_eq!(transport.derivations.load(Ordering::SeqCst), 0);
+        assert_eq!(client.api_creds().api_key, "key");
+    }
+
+    #[tokio::test]
+    async fn test_get_earnings_follows_cursor() {
+        let earning = r#"{"date":"2024-06-03","condition_id":"0xabc","asset_address":"0xusdc",
+            "maker_address":"0xmaker","earnings":1.25,"asset_rate":1}"#;
+        let server = TestServer::start(vec![
+            MockResponse::json(
+                200,
+                &format!(r#"{{"next_cursor":"MQ==","data":[{}]}}"#, earning),
+            ),
+            MockResponse::json(
+                200,
+                &format!(r#"{{"next_cursor":"LTE=","data":[{}]}}"#, earning),
+            ),
+        ])
+        .await;
+        let client = client(&server.url);
+
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+        let earnings = client
+            .get_earnings(EarningsParams::new(date))
+            .await
+            .unwrap();
+        assert_eq!(earnings.len(), 2);
+        assert_eq!(earnings[0].earnings, dec!(1.25));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/rewards/user?date=2024-06-03&next_cursor=MA%3D%3D"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/rewards/user?date=2024-06-03&next_cursor=MQ%3D%3D"
+        );
+        assert!(requests[0].header("POLY_API_KEY").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_with_market_filter() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"canceled":[],"not_canceled":null}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let response = client.cancel_all(Some("0xmarket")).await.unwrap();
+        assert!(response.canceled.is_empty());
+        assert!(response.not_canceled.is_empty());
+
+        client.cancel_all(None).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/cancel-market-orders");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["market"], "0xmarket");
+        assert_eq!(requests[1].path, "/cancel-all");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_asset_orders_without_open_orders() {
+        let server = TestServer::start(vec![MockResponse::json(200, "{}")]).await;
+        let client = client(&server.url);
+
+        let response = client.cancel_asset_orders("123").await.unwrap();
+        assert!(response.canceled.is_empty());
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/cancel-market-orders");
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(body["market"], "");
+        assert_eq!(body["asset_id"], "123");
+    }
+
+    #[tokio::test]
+    async fn test_get_open_orders_follows_pages_and_filters_side() {
+        let order = |id: &str, side: &str| {
+            format!(
+                r#"{{"id":"{}","associate_trades":[],"status":"LIVE","market":"0xm","original_size":"10","outcome":"Yes","maker_address":"0xa","owner":"key","price":"0.5","side":"{}","size_matched":"2","asset_id":"1","expiration":"0","order_type":"GTC","created_at":"1700000000"}}"#,
+                id, side
+            )
+        };
+        let page = |orders: Vec<String>, cursor: &str| {
+            format!(
+                r#"{{"limit":2,"count":{},"next_cursor":"{}","data":[{}]}}"#,
+                orders.len(),
+                cursor,
+                orders.join(",")
+            )
+        };
+        let server = TestServer::start(vec![
+            MockResponse::json(
+                200,
+                &page(vec![order("0x1", "BUY"), order("0x2", "SELL")], "Mg=="),
+            ),
+            MockResponse::json(200, &page(vec![order("0x3", "BUY")], "LTE=")),
+        ])
+        .await;
+        let client = client(&server.url);
+
+        let params = OpenOrderParams::new().market("0xm").side(Side::Buy);
+        let orders = client.get_open_orders(params).await.unwrap();
+
+        let ids: Vec<_> = orders.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, vec!["0x1", "0x3"]);
+        assert_eq!(orders[0].size_matched, dec!(2));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/data/orders?market=0xm&next_cursor=MA%3D%3D"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/data/orders?market=0xm&next_cursor=Mg%3D%3D"
+        );
+    }
+
+    #[test]
+    fn test_path_with_query_encodes_values() {
+        assert_eq!(path_with_query("/data/orders", &[]), "/data/orders");
+        assert_eq!(
+            path_with_query(
+                "/data/orders",
+                &[
+                    ("market", "0xm&id=1".to_string()),
+                    ("next_cursor", "MA==".to_string())
+                ]
+            ),
+            "/data/orders?market=0xm%26id%3D1&next_cursor=MA%3D%3D"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_typed_and_paginated() {
+        let first = r#"{"limit":1,"count":1,"next_cursor":"MQ==","data":[{
+            "id":"t1","taker_order_id":"0xo","market":"0xm","asset_id":"1","side":"BUY",
+            "size":"10","fee_rate_bps":"0","price":"0.5","status":"CONFIRMED",
+            "match_time":"1700000000","last_update":"1700000010","outcome":"Yes",
+            "maker_address":"0xa","owner":"key","transaction_hash":"0xh","trader_side":"TAKER",
+            "maker_orders":[{"maker_address":"0xb","matched_amount":"10","price":"0.5","outcome":"Yes"}]
+        }]}"#;
+        let second = r#"{"next_cursor":"LTE=","data":[{
+            "id":"t2","market":"0xm","asset_id":"1","side":"SELL","size":"4","price":"0.6",
+            "status":"MATCHED"
+        }]}"#;
+        let server = TestServer::start(vec![
+            MockResponse::json(200, first),
+            MockResponse::json(200, second),
+        ])
+        .await;
+        let client = client(&server.url);
+
+        let trades = client
+            .get_trades(TradeParams::new().market("0xm"))
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].match_time, Some(1_700_000_000));
+        assert_eq!(trades[0].fee_rate_bps, Some(dec!(0)));
+        assert_eq!(trades[0].maker_orders[0].matched_amount, dec!(10));
+        assert_eq!(trades[1].side, Side::Sell);
+        assert_eq!(trades[1].maker_address, None);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path,
+            "/data/trades?market=0xm&next_cursor=MA%3D%3D"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/data/trades?market=0xm&next_cursor=MQ%3D%3D"
+        );
+        assert!(requests[0].header("POLY_SIGNATURE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_post_order_error_status() {
+        let server = TestServer::start(vec![MockResponse::json(
+            400,
+            r#"{"error":"invalid order: price breaks minimum tick size rule"}"#,
+        )])
+        .await;
+        let client = client(&server.url);
+
+        let err = client
+            .post_order(signed_order(&client), OrderType::Gtc)
             .await
+            .unwrap_err();
+        assert!(matches!(err, Error::OrderRejected { .. }));
+        // Rejections are never retried
+        assert_eq!(server.requests().len(), 1);
     }
 }
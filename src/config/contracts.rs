@@ -57,6 +57,35 @@ pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Result<ContractConf
     }
 }
 
+/// Factories of the smart-contract wallets Polymarket deploys for its users
+#[derive(Debug, Clone)]
+pub struct WalletConfig {
+    /// Factory of the Polymarket proxy wallets (Magic/email accounts), if deployed on the chain
+    pub proxy_factory: Option<String>,
+    /// Factory of the Gnosis Safe wallets (browser wallet accounts)
+    pub safe_factory: String,
+}
+
+/// Get the wallet factories for a specific chain
+///
+/// # Returns
+/// * `Ok(WalletConfig)` - The wallet factories
+/// * `Err(Error::Config)` - If the chain is not supported
+pub fn get_wallet_config(chain_id: u64) -> Result<WalletConfig> {
+    match chain_id {
+        chains::POLYGON_MAINNET => Ok(WalletConfig {
+            proxy_factory: Some("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052".to_owned()),
+            safe_factory: "0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b".to_owned(),
+        }),
+        // Proxy wallets are only deployed on mainnet
+        chains::POLYGON_AMOY_TESTNET => Ok(WalletConfig {
+            proxy_factory: None,
+            safe_factory: "0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b".to_owned(),
+        }),
+        _ => Err(Error::Config(format!("Unsupported chain_id {}", chain_id))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
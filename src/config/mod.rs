@@ -1,3 +1,7 @@
 mod contracts;
+mod network;
+mod urls;
 
-pub use contracts::{chains, get_contract_config, ContractConfig};
+pub use contracts::{chains, get_contract_config, get_wallet_config, ContractConfig, WalletConfig};
+pub use network::Network;
+pub use urls::{CLOB_STAGING_URL, CLOB_URL, DATA_API_URL, GAMMA_API_URL};
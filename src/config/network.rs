@@ -0,0 +1,127 @@
+use super::contracts::{chains, get_contract_config, ContractConfig};
+use super::urls::{CLOB_STAGING_URL, CLOB_URL, DATA_API_URL, GAMMA_API_URL};
+use crate::error::{Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+/// Network orders are signed and settled on
+///
+/// Selects the chain ID of the EIP-712 domain, the exchange contracts and
+/// the default CLOB host:
+///
+/// | Network          | Chain ID | CLOB host                  |
+/// | ---------------- | -------- | -------------------------- |
+/// | `PolygonMainnet` | 137      | [`CLOB_URL`]               |
+/// | `Amoy`           | 80002    | [`CLOB_STAGING_URL`]       |
+///
+/// The Gamma and Data APIs only index mainnet, so both networks use the
+/// production hosts for them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// Polygon mainnet (chain ID 137)
+    #[default]
+    PolygonMainnet,
+    /// Polygon Amoy testnet (chain ID 80002)
+    Amoy,
+}
+
+impl Network {
+    /// Chain ID used in the EIP-712 domain of orders and auth messages
+    pub const fn chain_id(self) -> u64 {
+        match self {
+            Network::PolygonMainnet => chains::POLYGON_MAINNET,
+            Network::Amoy => chains::POLYGON_AMOY_TESTNET,
+        }
+    }
+
+    /// Network with the given chain ID
+    ///
+    /// # Errors
+    /// Returns [`Error::Config`] for chains Polymarket is not deployed on.
+    pub fn from_chain_id(chain_id: u64) -> Result<Self> {
+        match chain_id {
+            chains::POLYGON_MAINNET => Ok(Network::PolygonMainnet),
+            chains::POLYGON_AMOY_TESTNET => Ok(Network::Amoy),
+            _ => Err(Error::Config(format!("Unsupported chain_id {}", chain_id))),
+        }
+    }
+
+    /// Default base URL of the CLOB API
+    pub const fn clob_url(self) -> &'static str {
+        match self {
+            Network::PolygonMainnet => CLOB_URL,
+            Network::Amoy => CLOB_STAGING_URL,
+        }
+    }
+
+    /// Default base URL of the Gamma API
+    pub const fn gamma_url(self) -> &'static str {
+        GAMMA_API_URL
+    }
+
+    /// Default base URL of the Data API
+    pub const fn data_url(self) -> &'static str {
+        DATA_API_URL
+    }
+
+    /// Exchange and token contracts of this network
+    pub fn contract_config(self, neg_risk: bool) -> Result<ContractConfig> {
+        get_contract_config(self.chain_id(), neg_risk)
+    }
+}
+
+impl From<Network> for u64 {
+    fn from(network: Network) -> Self {
+        network.chain_id()
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Network::PolygonMainnet => "mainnet",
+            Network::Amoy => "amoy",
+        })
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    /// Parse `mainnet`/`polygon`/`137` or `amoy`/`testnet`/`80002`, ignoring case
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "mainnet" | "polygon" | "137" => Ok(Network::PolygonMainnet),
+            "amoy" | "testnet" | "80002" => Ok(Network::Amoy),
+            other => Err(Error::Config(format!("Unknown network '{}'", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_ids_and_hosts() {
+        assert_eq!(Network::PolygonMainnet.chain_id(), 137);
+        assert_eq!(Network::Amoy.chain_id(), 80002);
+        assert_eq!(u64::from(Network::Amoy), 80002);
+        assert_eq!(
+            Network::from_chain_id(137).unwrap(),
+            Network::PolygonMainnet
+        );
+        assert!(Network::from_chain_id(1).is_err());
+        assert_ne!(Network::PolygonMainnet.clob_url(), Network::Amoy.clob_url());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("Amoy".parse::<Network>().unwrap(), Network::Amoy);
+        assert_eq!("137".parse::<Network>().unwrap(), Network::PolygonMainnet);
+        for network in [Network::PolygonMainnet, Network::Amoy] {
+            assert_eq!(network.to_string().parse::<Network>().unwrap(), network);
+        }
+        assert!("goerli".parse::<Network>().is_err());
+    }
+}
@@ -0,0 +1,13 @@
+//! Default base URLs of the Polymarket APIs
+
+/// CLOB API (market data, orders, API keys)
+pub const CLOB_URL: &str = "https://clob.polymarket.com";
+
+/// Gamma API (market discovery and metadata)
+pub const GAMMA_API_URL: &str = "https://gamma-api.polymarket.com";
+
+/// Data API (positions, activity, portfolio value)
+pub const DATA_API_URL: &str = "https://data-api.polymarket.com";
+
+/// Staging CLOB API, settling on the Polygon Amoy testnet
+pub const CLOB_STAGING_URL: &str = "https://clob-staging.polymarket.com";
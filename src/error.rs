@@ -1,4 +1,5 @@
 use std::fmt;
+use std::time::Duration;
 
 /// Result type for polymarket-rs operations
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,9 +10,25 @@ pub enum Error {
     /// HTTP request failed
     Http(reqwest::Error),
 
+    /// Could not connect through the configured proxy
+    Proxy {
+        /// Proxy URL with the password masked
+        proxy: String,
+        source: reqwest::Error,
+    },
+
+    /// Request or operation timed out
+    Timeout(String),
+
     /// JSON serialization/deserialization failed
     Json(serde_json::Error),
 
+    /// A response body could not be deserialized into the expected type
+    Deserialization {
+        body: String,
+        source: serde_json::Error,
+    },
+
     /// Invalid configuration
     Config(String),
 
@@ -27,12 +44,38 @@ pub enum Error {
     /// API error response
     Api { status: u16, message: String },
 
+    /// The account lacks the balance or allowance for the request
+    InsufficientBalance(String),
+
+    /// The exchange rejected the order
+    OrderRejected { reason: String },
+
+    /// Too many requests (HTTP 429)
+    RateLimited { retry_after: Option<Duration> },
+
     /// Decimal conversion error
     Decimal(rust_decimal::Error),
 
     /// Invalid order configuration
     InvalidOrder(String),
 
+    /// An order was canceled but its replacement could not be posted
+    ///
+    /// Returned by [`TradingClient::replace_order`](crate::client::TradingClient::replace_order):
+    /// the account no longer has a quote for `canceled`, and `source` tells
+    /// why the new order failed.
+    ReplaceFailed {
+        canceled: crate::types::OrderId,
+        source: Box<Error>,
+    },
+
+    /// Market order would execute beyond the allowed slippage
+    SlippageExceeded {
+        best_price: rust_decimal::Decimal,
+        execution_price: rust_decimal::Decimal,
+        max_slippage_bps: u32,
+    },
+
     /// Missing required field
     MissingField(String),
 
@@ -53,7 +96,15 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Http(e) => write!(f, "HTTP error: {}", e),
+            Error::Proxy { proxy, source } => write!(f, "Proxy error ({}): {}", proxy, source),
+            Error::Timeout(msg) => write!(f, "Timeout: {}", msg),
             Error::Json(e) => write!(f, "JSON error: {}", e),
+            Error::Deserialization { body, source } => write!(
+                f,
+                "Failed to deserialize response: {} (body: {})",
+                source,
+                body.chars().take(200).collect::<String>()
+            ),
             Error::Config(msg) => write!(f, "Configuration error: {}", msg),
             Error::AuthRequired(msg) => write!(f, "Authentication required: {}", msg),
             Error::Signing(msg) => write!(f, "Signing error: {}", msg),
@@ -61,8 +112,29 @@ impl fmt::Display for Error {
             Error::Api { status, message } => {
                 write!(f, "API error (status {}): {}", status, message)
             }
+            Error::InsufficientBalance(msg) => write!(f, "Insufficient balance: {}", msg),
+            Error::OrderRejected { reason } => write!(f, "Order rejected: {}", reason),
+            Error::RateLimited { retry_after } => match retry_after {
+                Some(delay) => write!(f, "Rate limited, retry after {:?}", delay),
+                None => write!(f, "Rate limited"),
+            },
             Error::Decimal(e) => write!(f, "Decimal error: {}", e),
             Error::InvalidOrder(msg) => write!(f, "Invalid order: {}", msg),
+            Error::ReplaceFailed { canceled, source } => write!(
+                f,
+                "Order {} was canceled but its replacement failed: {}",
+                canceled.as_str(),
+                source
+            ),
+            Error::SlippageExceeded {
+                best_price,
+                execution_price,
+                max_slippage_bps,
+            } => write!(
+                f,
+                "Slippage exceeded: execution price {} deviates from best price {} by more than {} bps",
+                execution_price, best_price, max_slippage_bps
+            ),
             Error::MissingField(field) => write!(f, "Missing required field: {}", field),
             Error::WebSocket(msg) => write!(f, "WebSocket error: {}", msg),
             Error::ConnectionClosed => write!(f, "WebSocket connection closed"),
@@ -78,12 +150,90 @@ impl fmt::Display for Error {
     }
 }
 
+impl Error {
+    /// Whether the failed operation is worth retrying
+    ///
+    /// True for timeouts, connection failures, rate limiting (429) and server
+    /// errors (5xx); false for validation errors, other 4xx responses and
+    /// deserialization failures.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http(e) | Error::Proxy { source: e, .. } => e.is_connect() || e.is_timeout(),
+            Error::Timeout(_) | Error::RateLimited { .. } | Error::ConnectionClosed => true,
+            Error::Api { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Classify a non-success API response
+    ///
+    /// Polymarket error bodies look like `{"error": "..."}` (or `errorMsg` /
+    /// `message`). Only responses to order placement (`order_request`) map to
+    /// [`Error::InsufficientBalance`] or [`Error::OrderRejected`]: a 400 there
+    /// means the exchange refused the order. Every other failure becomes
+    /// [`Error::Api`] with the raw body, whatever its message says.
+    pub(crate) fn from_response(
+        status: u16,
+        retry_after: Option<Duration>,
+        body: String,
+        order_request: bool,
+    ) -> Self {
+        if status == 429 {
+            return Error::RateLimited { retry_after };
+        }
+
+        match error_message(&body) {
+            Some(message) if order_request && status == 400 => Error::order_rejected(message),
+            _ => Error::Api {
+                status,
+                message: body,
+            },
+        }
+    }
+
+    /// Classify an order the exchange accepted the request for but rejected
+    ///
+    /// Used for `success: false` entries of an order response, where the
+    /// reason comes from `errorMsg` rather than the HTTP status.
+    pub(crate) fn order_rejected(reason: String) -> Self {
+        if is_balance_message(&reason) {
+            Error::InsufficientBalance(reason)
+        } else {
+            Error::OrderRejected { reason }
+        }
+    }
+}
+
+/// Whether an order error is the exchange's balance/allowance failure
+///
+/// Matches the `INVALID_ORDER_NOT_ENOUGH_BALANCE` code and the messages the
+/// CLOB sends with it.
+fn is_balance_message(reason: &str) -> bool {
+    let lower = reason.to_lowercase();
+    reason.starts_with("INVALID_ORDER_NOT_ENOUGH_BALANCE")
+        || lower.contains("not enough balance")
+        || lower.contains("insufficient balance")
+}
+
+/// Extract the message from a JSON error body
+fn error_message(body: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    ["error", "errorMsg", "message"]
+        .iter()
+        .find_map(|key| value.get(key)?.as_str())
+        .filter(|message| !message.is_empty())
+        .map(str::to_string)
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::Http(e) => Some(e),
+            Error::Proxy { source, .. } => Some(source),
             Error::Json(e) => Some(e),
+            Error::Deserialization { source, .. } => Some(source),
             Error::Decimal(e) => Some(e),
+            Error::ReplaceFailed { source, .. } => Some(source.as_ref()),
             _ => None,
         }
     }
@@ -91,7 +241,11 @@ impl std::error::Error for Error {
 
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
-        Error::Http(err)
+        if err.is_timeout() {
+            Error::Timeout(err.to_string())
+        } else {
+            Error::Http(err)
+        }
     }
 }
 
@@ -118,3 +272,174 @@ impl From<tokio_tungstenite::tungstenite::Error> for Error {
         Error::WebSocket(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable() {
+        let json_err = serde_json::from_str::<u32>("x").unwrap_err();
+        let decimal_err = "x".parse::<rust_decimal::Decimal>().unwrap_err();
+        // A request that fails to build, not a connect error
+        let builder_err = || reqwest::Client::new().get("not a url").build().unwrap_err();
+        let api = |status| Error::Api {
+            status,
+            message: String::new(),
+        };
+
+        let cases = vec![
+            (Error::Timeout("t".into()), true),
+            (
+                Error::RateLimited {
+                    retry_after: Some(Duration::from_secs(1)),
+                },
+                true,
+            ),
+            (Error::ConnectionClosed, true),
+            (api(429), true),
+            (api(500), true),
+            (api(503), true),
+            (api(400), false),
+            (api(404), false),
+            (Error::Http(builder_err()), false),
+            (
+                Error::Proxy {
+                    proxy: "http://proxy:8080".into(),
+                    source: builder_err(),
+                },
+                false,
+            ),
+            (Error::Json(json_err), false),
+            (
+                Error::Deserialization {
+                    body: "x".into(),
+                    source: serde_json::from_str::<u32>("x").unwrap_err(),
+                },
+                false,
+            ),
+            (Error::Decimal(decimal_err), false),
+            (Error::Config("c".into()), false),
+            (Error::AuthRequired("a".into()), false),
+            (Error::Signing("s".into()), false),
+            (Error::InvalidParameter("p".into()), false),
+            (Error::InsufficientBalance("b".into()), false),
+            (Error::OrderRejected { reason: "r".into() }, false),
+            (Error::InvalidOrder("o".into()), false),
+            // The cancel went through, so the replace as a whole isn't retried
+            (
+                Error::ReplaceFailed {
+                    canceled: crate::types::OrderId::new("0x1"),
+                    source: Box::new(Error::Timeout("t".into())),
+                },
+                false,
+            ),
+            (
+                Error::SlippageExceeded {
+                    best_price: rust_decimal::Decimal::ONE,
+                    execution_price: rust_decimal::Decimal::TWO,
+                    max_slippage_bps: 0,
+                },
+                false,
+            ),
+            (Error::MissingField("f".into()), false),
+            (Error::WebSocket("w".into()), false),
+            (
+                Error::ReconnectFailed {
+                    attempts: 3,
+                    last_error: "e".into(),
+                },
+                false,
+            ),
+        ];
+
+        for (err, expected) in cases {
+            assert_eq!(err.is_retryable(), expected, "{:?}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_is_retryable_connect_error() {
+        // Nothing listens on port 1
+        let source = reqwest::get("http://127.0.0.1:1").await.unwrap_err();
+        let err = Error::Proxy {
+            proxy: "http://127.0.0.1:1".into(),
+            source,
+        };
+        assert!(err.is_retryable());
+
+        let err: Error = reqwest::get("http://127.0.0.1:1").await.unwrap_err().into();
+        assert!(matches!(err, Error::Http(_)));
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_from_response_insufficient_balance() {
+        let err = Error::from_response(
+            400,
+            None,
+            r#"{"error":"not enough balance / allowance"}"#.to_string(),
+            true,
+        );
+        assert!(matches!(err, Error::InsufficientBalance(msg) if msg.contains("allowance")));
+    }
+
+    #[test]
+    fn test_from_response_order_rejected() {
+        let err = Error::from_response(
+            400,
+            None,
+            r#"{"errorMsg":"invalid order: price breaks minimum tick size rule"}"#.to_string(),
+            true,
+        );
+        assert!(
+            matches!(err, Error::OrderRejected { reason } if reason.starts_with("invalid order"))
+        );
+    }
+
+    #[test]
+    fn test_from_response_structured_balance_code() {
+        let body = r#"{"errorMsg":"INVALID_ORDER_NOT_ENOUGH_BALANCE"}"#.to_string();
+        let err = Error::from_response(400, None, body, true);
+        assert!(matches!(err, Error::InsufficientBalance(_)));
+    }
+
+    #[test]
+    fn test_from_response_non_order_400_is_api() {
+        for body in [
+            r#"{"error":"invalid order id"}"#,
+            r#"{"error":"order not found"}"#,
+            r#"{"error":"not enough balance / allowance"}"#,
+            r#"{"error":"invalid token id"}"#,
+        ] {
+            let err = Error::from_response(400, None, body.to_string(), false);
+            assert!(
+                matches!(&err, Error::Api { status: 400, message } if message == body),
+                "{body}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_response_rate_limited() {
+        let err = Error::from_response(429, Some(Duration::from_secs(2)), String::new(), false);
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(d)
+            } if d == Duration::from_secs(2)
+        ));
+    }
+
+    #[test]
+    fn test_from_response_falls_back_to_api() {
+        let err = Error::from_response(500, None, "upstream timeout".to_string(), true);
+        assert!(
+            matches!(err, Error::Api { status: 500, message } if message == "upstream timeout")
+        );
+
+        let body = r#"{"error":"market not found"}"#.to_string();
+        let err = Error::from_response(404, None, body.clone(), false);
+        assert!(matches!(err, Error::Api { status: 404, message } if message == body));
+    }
+}
@@ -1,9 +1,9 @@
 use crate::error::Result;
 use crate::signing::{sign_clob_auth_message, EthSigner};
 use crate::types::ApiCreds;
-use crate::utils::{build_hmac_signature, get_current_unix_time_secs};
+use crate::utils::build_hmac_signature;
 use alloy_primitives::hex::encode_prefixed;
-use alloy_primitives::U256;
+use alloy_primitives::{Address, U256};
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -16,16 +16,17 @@ const POLY_PASS_HEADER: &str = "POLY_PASSPHRASE";
 
 pub type Headers = HashMap<&'static str, String>;
 
-/// Create L1 headers for authentication (EIP-712 based)
+/// Build L1 headers for authentication (EIP-712 based), signed at `timestamp`
 ///
 /// These headers are used for operations that require wallet signature,
 /// such as creating API keys.
-pub fn create_l1_headers<S: EthSigner>(
+pub(crate) fn build_l1_headers<S: EthSigner>(
     signer: &S,
     chain_id: u64,
     nonce: Option<U256>,
+    timestamp: u64,
 ) -> Result<Headers> {
-    let timestamp = get_current_unix_time_secs()?.to_string();
+    let timestamp = timestamp.to_string();
     let nonce = nonce.unwrap_or(U256::ZERO);
     let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce, chain_id)?;
     let address = encode_prefixed(signer.address().as_slice());
@@ -38,13 +39,14 @@ pub fn create_l1_headers<S: EthSigner>(
     ]))
 }
 
-/// Create L2 headers for authenticated requests (HMAC based)
+/// Build L2 headers for authenticated requests (HMAC based), signed at `timestamp`
 ///
 /// These headers are used for API operations that require API credentials,
 /// such as creating orders, querying private data, etc.
-pub fn create_l2_headers<S: EthSigner, T>(
-    signer: &S,
+pub(crate) fn build_l2_headers<T>(
+    address: Address,
     api_creds: &ApiCreds,
+    timestamp: u64,
     method: &str,
     req_path: &str,
     body: Option<&T>,
@@ -52,9 +54,7 @@ pub fn create_l2_headers<S: EthSigner, T>(
 where
     T: ?Sized + Serialize,
 {
-    let address = encode_prefixed(signer.address().as_slice());
-    let timestamp = get_current_unix_time_secs()?;
-
+    let address = encode_prefixed(address.as_slice());
     let hmac_signature =
         build_hmac_signature(&api_creds.secret, timestamp, method, req_path, body)?;
 
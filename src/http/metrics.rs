@@ -0,0 +1,45 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Callback invoked after every HTTP attempt, for metrics collection
+///
+/// Install one with [`HttpClientBuilder::metrics_hook`](super::HttpClientBuilder::metrics_hook).
+/// Retried attempts are reported individually, so 429 and 5xx responses that
+/// were later retried still show up. Without a hook no timing or bookkeeping
+/// is done.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::MetricsHook;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::time::Duration;
+///
+/// #[derive(Default)]
+/// struct RateLimitCounter(AtomicU64);
+///
+/// impl MetricsHook for RateLimitCounter {
+///     fn on_response(&self, _method: &str, _path: &str, status: Option<u16>, _elapsed: Duration) {
+///         if status == Some(429) {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait MetricsHook: Send + Sync {
+    /// Called once per attempt
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method, e.g. `GET`
+    /// * `path` - URL path without the query string
+    /// * `status` - Response status, or `None` if no response was received
+    /// * `elapsed` - Time from sending the request until the full response was
+    ///   received (or the attempt failed), body included
+    fn on_response(&self, method: &str, path: &str, status: Option<u16>, elapsed: Duration);
+}
+
+impl fmt::Debug for dyn MetricsHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MetricsHook")
+    }
+}
@@ -1,5 +1,17 @@
 mod client;
 mod headers;
+mod metrics;
+mod proxy;
+mod rate_limit;
+mod retry;
+#[cfg(test)]
+pub(crate) mod test_server;
+mod transport;
 
-pub use client::HttpClient;
-pub use headers::{create_l1_headers, create_l2_headers};
+pub use client::{HttpClient, HttpClientBuilder};
+pub(crate) use headers::{build_l1_headers, build_l2_headers, Headers};
+pub use metrics::MetricsHook;
+pub use rate_limit::{RateLimitInfo, RateLimiter};
+pub(crate) use retry::retry_with;
+pub use retry::RetryPolicy;
+pub use transport::{Transport, TransportFuture, TransportResponse};
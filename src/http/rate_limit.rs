@@ -0,0 +1,201 @@
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Rate-limit quota reported by the server in response headers
+///
+/// Captured from `x-ratelimit-limit`, `x-ratelimit-remaining` and
+/// `x-ratelimit-reset` when [`HttpClientBuilder::track_rate_limits`](crate::HttpClientBuilder::track_rate_limits)
+/// is enabled, see [`HttpClient::last_rate_limit`](crate::HttpClient::last_rate_limit).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window
+    pub limit: Option<u64>,
+    /// Requests left in the current window
+    pub remaining: Option<u64>,
+    /// Time until the window resets, measured from when the response arrived
+    pub reset_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parse the rate-limit headers, or `None` if the response has none
+    ///
+    /// The reset header may be either a number of seconds or a Unix
+    /// timestamp; values past the year 2001 are treated as timestamps.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number =
+            |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+        let info = Self {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            reset_after: number("x-ratelimit-reset").map(reset_after),
+        };
+        (info.limit.is_some() || info.remaining.is_some() || info.reset_after.is_some())
+            .then_some(info)
+    }
+}
+
+/// Convert a reset header value into the time left until the reset
+fn reset_after(value: u64) -> Duration {
+    const TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+    if value < TIMESTAMP_THRESHOLD {
+        return Duration::from_secs(value);
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    Duration::from_secs(value.saturating_sub(now))
+}
+
+/// Token-bucket rate limiter for outgoing requests
+///
+/// The bucket holds up to `burst` permits and refills at `requests_per_second`.
+/// Cloning the limiter shares the same bucket, so every clone of an
+/// [`HttpClient`](crate::HttpClient) built with it draws from one budget.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_second` with a burst of the same size
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is zero, negative or NaN, like
+    /// [`with_burst`](Self::with_burst).
+    pub fn new(requests_per_second: f64) -> Self {
+        Self::with_burst(requests_per_second, requests_per_second.max(1.0) as u32)
+    }
+
+    /// Create a limiter with an explicit burst capacity
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is zero, negative or NaN.
+    pub fn with_burst(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second > 0.0,
+            "requests_per_second must be positive"
+        );
+        let capacity = f64::from(burst.max(1));
+
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                capacity,
+                tokens: capacity,
+                refill_per_sec: requests_per_second,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Wait until a permit is available and take it
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                bucket.refill();
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_per_sec)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_is_immediate() {
+        let limiter = RateLimiter::with_burst(1.0, 3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_throttles_after_burst() {
+        let limiter = RateLimiter::with_burst(20.0, 1);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        // Two refills at 20 req/s take at least ~100ms
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_bucket() {
+        let limiter = RateLimiter::with_burst(20.0, 1);
+        let clone = limiter.clone();
+        let start = Instant::now();
+        limiter.acquire().await;
+        clone.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_rate() {
+        for rate in [0.0, -1.0, f64::NAN] {
+            let result = std::panic::catch_unwind(|| RateLimiter::new(rate));
+            assert!(result.is_err(), "{}", rate);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimitInfo::from_headers(&headers), None);
+
+        headers.insert("x-ratelimit-limit", "100".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "7".parse().unwrap());
+        assert_eq!(
+            RateLimitInfo::from_headers(&headers),
+            Some(RateLimitInfo {
+                limit: Some(100),
+                remaining: Some(42),
+                reset_after: Some(Duration::from_secs(7)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_reset_timestamp() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let reset = reset_after(now + 30);
+        assert!(reset <= Duration::from_secs(30) && reset >= Duration::from_secs(28));
+        assert_eq!(reset_after(now - 10), Duration::ZERO);
+    }
+}
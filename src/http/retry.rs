@@ -0,0 +1,305 @@
+use crate::error::{Error, Result};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use std::future::Future;
+use std::time::Duration;
+
+/// Retry policy for HTTP requests
+///
+/// Requests are retried on 429 and 5xx responses as well as connection and
+/// timeout errors. The delay before attempt `n + 1` is
+/// `base_delay * multiplier^(n - 1)`, unless the server sent a `Retry-After`
+/// header, in which case that value is used instead. Either delay is capped at
+/// `max_delay`.
+///
+/// Only idempotent requests are retried by default, since retrying a POST
+/// that reached the server may place an order twice:
+///
+/// | Method                          | Retried                                          |
+/// |---------------------------------|--------------------------------------------------|
+/// | GET, HEAD, PUT, DELETE, OPTIONS | Yes                                              |
+/// | POST, PATCH                     | Only with [`retry_non_idempotent`](Self::retry_non_idempotent) and an [`IDEMPOTENCY_KEY_HEADER`](Self::IDEMPOTENCY_KEY_HEADER) |
+///
+/// To change the policy of a single call, send it through
+/// [`HttpClient::with_retry_policy`](super::HttpClient::with_retry_policy).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one (1 = no retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Multiplier applied to the delay after each retry
+    pub multiplier: f64,
+    /// Longest delay between two attempts, also applied to `Retry-After`
+    pub max_delay: Duration,
+    /// Also retry POST and PATCH requests that carry an idempotency key
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            multiplier: 2.0,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Header that marks a POST or PATCH as safe to retry
+    ///
+    /// Requests without it are sent once even when
+    /// [`retry_non_idempotent`](Self::retry_non_idempotent) is set. The
+    /// server must deduplicate requests by this key for the retry to be safe.
+    pub const IDEMPOTENCY_KEY_HEADER: &'static str = "Idempotency-Key";
+
+    /// Default of [`max_delay`](Self::max_delay)
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+    /// Create a policy with the default `max_delay`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiplier` is not finite or is below 1.0.
+    pub fn new(max_attempts: u32, base_delay: Duration, multiplier: f64) -> Self {
+        assert!(
+            multiplier.is_finite() && multiplier >= 1.0,
+            "retry multiplier must be finite and at least 1.0, got {}",
+            multiplier
+        );
+        Self {
+            max_attempts,
+            base_delay,
+            multiplier,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Cap the delay between two attempts, including a server's `Retry-After`
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Opt in to retrying POST and PATCH requests that carry an idempotency key
+    pub fn with_retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Whether a request with this method and headers may be retried at all
+    pub(crate) fn allows(&self, method: &Method, headers: &HeaderMap) -> bool {
+        match *method {
+            Method::POST | Method::PATCH => {
+                self.retry_non_idempotent && headers.contains_key(Self::IDEMPOTENCY_KEY_HEADER)
+            }
+            _ => true,
+        }
+    }
+
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether another attempt is allowed after `attempt` attempts have been made
+    pub(crate) fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    /// Backoff delay to wait after the given (1-based) failed attempt
+    ///
+    /// A delay that overflows, or a multiplier set to a negative or non-finite
+    /// value through the public field, gives `max_delay`.
+    pub(crate) fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let secs = self.base_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::try_from_secs_f64(secs)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// `Retry-After` delay sent by the server, capped at `max_delay`
+    pub(crate) fn cap(&self, retry_after: Duration) -> Duration {
+        retry_after.min(self.max_delay)
+    }
+}
+
+/// Run `op` until it succeeds, retrying retryable errors as `policy` allows
+///
+/// Waits for the `retry_after` of [`Error::RateLimited`] when the server sent
+/// one, and the policy's backoff otherwise. The last error is returned once
+/// the attempts are exhausted.
+pub(crate) async fn retry_with<T, F, Fut>(policy: &RetryPolicy, mut op: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e) if e.is_retryable() && policy.should_retry(attempt) => {
+                let delay = match e {
+                    Error::RateLimited {
+                        retry_after: Some(delay),
+                    } => policy.cap(delay),
+                    _ => policy.delay_for(attempt),
+                };
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+        attempt += 1;
+    }
+}
+
+/// Whether a response status should be retried
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parse a `Retry-After` header expressed in whole seconds
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_delay_for() {
+        let policy = RetryPolicy::new(4, Duration::from_millis(100), 2.0);
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_delay_for_is_capped() {
+        let policy = RetryPolicy::new(u32::MAX, Duration::from_secs(1), 10.0)
+            .with_max_delay(Duration::from_secs(5));
+        assert_eq!(policy.delay_for(2), Duration::from_secs(5));
+        // 10^1000 overflows f64 and Duration
+        assert_eq!(policy.delay_for(1000), Duration::from_secs(5));
+        assert_eq!(policy.delay_for(u32::MAX), Duration::from_secs(5));
+        assert_eq!(policy.cap(Duration::from_secs(60)), Duration::from_secs(5));
+
+        for multiplier in [-2.0, f64::NAN, f64::INFINITY] {
+            let policy = RetryPolicy {
+                multiplier,
+                ..RetryPolicy::default()
+            };
+            assert_eq!(
+                policy.delay_for(2),
+                RetryPolicy::DEFAULT_MAX_DELAY,
+                "{}",
+                multiplier
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_multiplier() {
+        for multiplier in [0.5, -1.0, f64::NAN, f64::INFINITY] {
+            let result =
+                std::panic::catch_unwind(|| RetryPolicy::new(3, Duration::ZERO, multiplier));
+            assert!(result.is_err(), "{}", multiplier);
+        }
+    }
+
+    #[test]
+    fn test_allows_idempotent_methods_only() {
+        let policy = RetryPolicy::default();
+        let mut keyed = HeaderMap::new();
+        keyed.insert(
+            RetryPolicy::IDEMPOTENCY_KEY_HEADER,
+            HeaderValue::from_static("order-1"),
+        );
+
+        for method in [Method::GET, Method::HEAD, Method::PUT, Method::DELETE] {
+            assert!(policy.allows(&method, &HeaderMap::new()), "{}", method);
+        }
+        assert!(!policy.allows(&Method::POST, &keyed));
+        assert!(!policy.allows(&Method::PATCH, &keyed));
+
+        let opted_in = policy.with_retry_non_idempotent(true);
+        assert!(opted_in.allows(&Method::POST, &keyed));
+        assert!(!opted_in.allows(&Method::POST, &HeaderMap::new()));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new(3, Duration::ZERO, 1.0);
+        assert!(policy.should_retry(1));
+        assert!(policy.should_retry(2));
+        assert!(!policy.should_retry(3));
+        assert!(!RetryPolicy::none().should_retry(1));
+    }
+
+    #[tokio::test]
+    async fn test_retry_with() {
+        let policy = RetryPolicy::new(3, Duration::ZERO, 1.0);
+        let calls = std::cell::Cell::new(0);
+        let result = retry_with(&policy, || async {
+            calls.set(calls.get() + 1);
+            match calls.get() {
+                1 => Err(Error::Api {
+                    status: 502,
+                    message: "bad gateway".to_string(),
+                }),
+                n => Ok(n),
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+
+        // Non-retryable errors are returned immediately
+        calls.set(0);
+        let result: Result<()> = retry_with(&policy, || async {
+            calls.set(calls.get() + 1);
+            Err(Error::InvalidParameter("bad".to_string()))
+        })
+        .await;
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+}
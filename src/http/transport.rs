@@ -0,0 +1,109 @@
+use super::proxy::ProxyConfig;
+use crate::error::Result;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Request, StatusCode};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by [`Transport::execute`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse>> + Send + 'a>>;
+
+/// Sends requests on behalf of an [`HttpClient`](super::HttpClient)
+///
+/// The default transport sends requests over the network with reqwest.
+/// Tests can install a fake one with
+/// [`HttpClientBuilder::transport`](super::HttpClientBuilder::transport) to
+/// return canned responses; retries, rate limiting and error mapping still
+/// apply on top of it. Requests passed to the transport already carry the
+/// default headers of the client.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::{HttpClient, Transport, TransportFuture, TransportResponse};
+/// use reqwest::{Request, StatusCode};
+///
+/// struct Canned;
+///
+/// impl Transport for Canned {
+///     fn execute(&self, request: Request) -> TransportFuture<'_> {
+///         let body = format!(r#"{{"path":"{}"}}"#, request.url().path());
+///         Box::pin(async move { Ok(TransportResponse::new(StatusCode::OK, body)) })
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = HttpClient::builder("http://mock").transport(Canned).build()?;
+/// let value = client.get_raw("/book", None).await?;
+/// assert_eq!(value["path"], "/book");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send `request` and buffer the response
+    fn execute(&self, request: Request) -> TransportFuture<'_>;
+}
+
+impl fmt::Debug for dyn Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transport")
+    }
+}
+
+/// Buffered HTTP response returned by a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Add a response header; invalid names or values are ignored
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (name.parse::<reqwest::header::HeaderName>(), value.parse())
+        {
+            self.headers.append(name, value);
+        }
+        self
+    }
+}
+
+/// Default transport sending requests with reqwest
+pub(crate) struct ReqwestTransport {
+    pub(crate) client: Client,
+    pub(crate) proxy: Option<ProxyConfig>,
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .execute(request)
+                .await
+                .map_err(|e| match &self.proxy {
+                    Some(proxy) => proxy.map_error(e),
+                    None => e.into(),
+                })?;
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
@@ -36,14 +36,23 @@ mod utils;
 pub use alloy_primitives::Address;
 pub use alloy_signer::k256;
 pub use alloy_signer_local::PrivateKeySigner;
+pub use config::Network;
 pub use error::{Error, Result};
 pub use types::{
-    ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
-    OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
+    ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, L2Credentials,
+    MarketOrderArgs, OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
+};
+
+// Re-export HTTP client configuration
+pub use http::{
+    HttpClient, HttpClientBuilder, MetricsHook, RateLimitInfo, RateLimiter, RetryPolicy, Transport,
+    TransportFuture, TransportResponse,
 };
 
 // Re-export clients
-pub use client::{AuthenticatedClient, ClobClient, DataClient, GammaClient, TradingClient};
+pub use client::{
+    AuthenticatedClient, ClobClient, DataClient, GammaClient, PolymarketClient, TradingClient,
+};
 
 // Re-export websocket clients
 pub use websocket::{MarketWsClient, UserWsClient};
@@ -52,7 +61,7 @@ pub use websocket::{MarketWsClient, UserWsClient};
 pub use orders::OrderBuilder;
 
 // Re-export signer trait
-pub use signing::EthSigner;
+pub use signing::{EthSigner, NonceManager};
 
 // Re-export stream extension traits
 pub use futures_util::StreamExt;
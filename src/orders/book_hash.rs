@@ -0,0 +1,102 @@
+use crate::types::PriceLevel;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+#[derive(Serialize)]
+struct HashedBook<'a> {
+    market: &'a str,
+    asset_id: &'a str,
+    timestamp: String,
+    bids: &'a [PriceLevel],
+    asks: &'a [PriceLevel],
+    hash: &'a str,
+}
+
+/// Compute the hash Polymarket reports in the `hash` field of a book
+///
+/// Computes the SHA-1 hex digest of the compact JSON book, with the fields in
+/// the order `market`, `asset_id`, `timestamp`, `bids`, `asks` and an empty
+/// `hash`. The algorithm is not yet pinned against a captured server
+/// response (run the ignored `test_live_book_hash` to check it), so treat a
+/// mismatch with the server's `hash` as inconclusive. Prices and
+/// sizes are hashed as the strings the API sent, so pass the levels exactly
+/// as received: sorting, merging or truncating them, or rescaling a price
+/// (`0.5` vs `0.50`), changes the hash. The server sends bids ascending and
+/// asks descending; [`OrderBookSummary::verify_hash`](crate::types::OrderBookSummary::verify_hash)
+/// restores that order for books sorted best price first.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::compute_book_hash;
+/// use polymarket_rs::types::PriceLevel;
+/// use rust_decimal_macros::dec;
+///
+/// let bids = vec![PriceLevel::new(dec!(0.3), dec!(100)), PriceLevel::new(dec!(0.4), dec!(100))];
+/// let asks = vec![PriceLevel::new(dec!(0.6), dec!(100)), PriceLevel::new(dec!(0.7), dec!(100))];
+/// let hash = compute_book_hash("0xaabbcc", "100", 123456789, &bids, &asks);
+/// assert_eq!(hash.len(), 40);
+/// ```
+pub fn compute_book_hash(
+    market: &str,
+    asset_id: &str,
+    timestamp: u64,
+    bids: &[PriceLevel],
+    asks: &[PriceLevel],
+) -> String {
+    let book = HashedBook {
+        market,
+        asset_id,
+        timestamp: timestamp.to_string(),
+        bids,
+        asks,
+        hash: "",
+    };
+    let json = serde_json::to_string(&book).expect("book serializes to JSON");
+
+    Sha1::digest(json.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::OrderBookSummary;
+
+    #[test]
+    fn test_hash_keeps_price_strings() {
+        // Self-consistency only: the hash below was computed by this function.
+        // `0.50` must be hashed as sent, not as `0.5`
+        let book: OrderBookSummary = serde_json::from_str(
+            r#"{"market":"0xaabbcc","asset_id":"100","timestamp":"123456789",
+                "hash":"ac8cd46b11f3f792f2fe3bcaf0d9e7052d7904ac",
+                "bids":[{"price":"0.50","size":"10"}],"asks":[{"price":"0.52","size":"7.5"}]}"#,
+        )
+        .unwrap();
+        assert!(book.verify_hash());
+
+        let mut changed = book;
+        changed.bids[0].size = 11.into();
+        assert!(!changed.verify_hash());
+    }
+
+    /// Checks the algorithm against the hash of a live book; on failure the
+    /// printed response is the vector to pin
+    #[tokio::test]
+    #[ignore = "needs network access to the CLOB"]
+    async fn test_live_book_hash() {
+        let client = crate::client::ClobClient::new(crate::config::CLOB_URL);
+        let markets = client.get_sampling_markets(None).await.unwrap();
+        let token_id = markets
+            .data
+            .iter()
+            .find(|market| market.accepting_orders)
+            .map(|market| market.tokens[0].token_id.clone())
+            .expect("an open market");
+
+        let url = format!("{}/book?token_id={}", crate::config::CLOB_URL, token_id);
+        let body = reqwest::get(&url).await.unwrap().text().await.unwrap();
+        let book: OrderBookSummary = serde_json::from_str(&body).unwrap();
+        assert_eq!(book.compute_hash(), book.hash, "book: {}", body);
+    }
+}
@@ -2,9 +2,9 @@ use super::rounding::{decimal_to_token_u64, fix_amount_rounding, ROUNDING_CONFIG
 use crate::config::get_contract_config;
 use crate::error::{Error, Result};
 use crate::orders::RoundConfig;
-use crate::signing::{sign_order_message, EthSigner, Order};
+use crate::signing::{funder_address, sign_order_message, EthSigner, NonceManager, Order};
 use crate::types::{
-    CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, Side, SignatureType,
+    CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, OrderType, Side, SignatureType,
     SignedOrderRequest,
 };
 use crate::utils::get_current_unix_time_secs;
@@ -13,6 +13,8 @@ use rand::{thread_rng, Rng};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::ToZero;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Generate a random seed for order salt
 fn generate_seed() -> Result<u64> {
@@ -23,11 +25,83 @@ fn generate_seed() -> Result<u64> {
     Ok(a as u64)
 }
 
+/// Expiration timestamp `lifetime` from now, for good-till-date (GTD) orders
+///
+/// The exchange applies a one-minute security threshold to GTD orders, so an
+/// order meant to rest for 30 seconds needs a `lifetime` of 90 seconds.
+pub fn expiration_in(lifetime: Duration) -> Result<u64> {
+    Ok(get_current_unix_time_secs()? + lifetime.as_secs())
+}
+
+/// Check `order_type` against a signed order and return the type to post it with
+///
+/// - An order with an expiration is good-till-date, so GTC becomes
+///   [`OrderType::Gtd`]; GTD without an expiration is refused.
+/// - FOK and FAK orders can't expire, and the exchange only accepts them with
+///   a maker amount of at most 2 decimals and a taker amount of at most 4.
+///
+/// # Errors
+/// Returns [`Error::InvalidOrder`] if the order can't be posted as `order_type`.
+pub fn resolve_order_type(order: &SignedOrderRequest, order_type: OrderType) -> Result<OrderType> {
+    let expires = !matches!(order.expiration.as_str(), "" | "0");
+    match (order_type, expires) {
+        (OrderType::Gtc, true) => Ok(OrderType::Gtd),
+        (OrderType::Gtd, false) => Err(Error::InvalidOrder(
+            "GTD orders need a non-zero expiration".to_string(),
+        )),
+        (OrderType::Fok | OrderType::Fak, true) => Err(Error::InvalidOrder(format!(
+            "{:?} orders can't have an expiration",
+            order_type
+        ))),
+        (OrderType::Fok | OrderType::Fak, false) => {
+            // Amounts are in 1e6 token units
+            check_amount_decimals(&order.maker_amount, "maker", 2, order_type)?;
+            check_amount_decimals(&order.taker_amount, "taker", 4, order_type)?;
+            Ok(order_type)
+        }
+        (OrderType::Gtc, false) | (OrderType::Gtd, true) => Ok(order_type),
+    }
+}
+
+fn check_amount_decimals(
+    amount: &str,
+    name: &str,
+    decimals: u32,
+    order_type: OrderType,
+) -> Result<()> {
+    let units: u64 = amount
+        .parse()
+        .map_err(|_| Error::InvalidOrder(format!("Invalid {} amount: {}", name, amount)))?;
+    if !units.is_multiple_of(10u64.pow(6 - decimals)) {
+        return Err(Error::InvalidOrder(format!(
+            "{:?} {} amount {} has more than {} decimals",
+            order_type,
+            name,
+            Decimal::new(units as i64, 6).normalize(),
+            decimals
+        )));
+    }
+    Ok(())
+}
+
 /// Builder for creating and signing orders
+///
+/// The signature type tells the exchange which wallet holds the funds:
+/// - [`SignatureType::Eoa`]: the signer's own address, which makes the order.
+/// - [`SignatureType::PolyProxy`]: the Polymarket proxy wallet of an email
+///   (Magic) account, which the signer controls.
+/// - [`SignatureType::PolyGnosisSafe`]: the Gnosis Safe of a browser wallet
+///   account, owned by the signer.
+///
+/// For proxy and Safe wallets the funder becomes the order maker while the
+/// signer signs it. Without an explicit funder, the wallet address is derived
+/// from the signer (see [`funder_address`]).
 pub struct OrderBuilder {
     signer: Box<dyn EthSigner>,
     sig_type: SignatureType,
-    funder: Address,
+    funder: Option<Address>,
+    nonce_manager: Option<Arc<NonceManager>>,
+    expiration: Option<Duration>,
 }
 
 impl OrderBuilder {
@@ -36,21 +110,76 @@ impl OrderBuilder {
     /// # Arguments
     /// * `signer` - The Ethereum signer to use for signing orders
     /// * `sig_type` - The signature type (defaults to EOA if None)
-    /// * `funder` - The address funding the order (defaults to signer address if None)
+    /// * `funder` - The address funding the order (defaults to the signer for
+    ///   EOA, and to the signer's proxy wallet or Safe otherwise)
     pub fn new(
         signer: impl EthSigner + 'static,
         sig_type: Option<SignatureType>,
         funder: Option<Address>,
     ) -> Self {
         let sig_type = sig_type.unwrap_or(SignatureType::Eoa);
-        let signer_addr = signer.address();
-        let funder = funder.unwrap_or(signer_addr);
 
         Self {
             signer: Box::new(signer),
             sig_type,
             funder,
+            nonce_manager: None,
+            expiration: None,
+        }
+    }
+
+    /// Make limit orders good-till-date, expiring `lifetime` after they are created
+    ///
+    /// Applies to orders created without an explicit expiration; see
+    /// [`expiration_in`] for the exchange's security threshold.
+    pub fn with_expiration(mut self, lifetime: Duration) -> Self {
+        self.expiration = Some(lifetime);
+        self
+    }
+
+    /// Expiration to sign a limit order with
+    ///
+    /// Zero means no expiration (GTC) unless a default lifetime is set.
+    fn order_expiration(&self, expiration: u64) -> Result<u64> {
+        if expiration == 0 {
+            return match self.expiration {
+                Some(lifetime) => expiration_in(lifetime),
+                None => Ok(0),
+            };
+        }
+        let now = get_current_unix_time_secs()?;
+        if expiration <= now {
+            return Err(Error::InvalidOrder(format!(
+                "Expiration {} is not in the future (now {})",
+                expiration, now
+            )));
+        }
+        Ok(expiration)
+    }
+
+    /// Sign every order with the current nonce of `nonce_manager`
+    ///
+    /// An order whose [`ExtraOrderArgs::nonce`] is set to a different non-zero
+    /// value is refused instead of being signed with a nonce the exchange
+    /// would reject.
+    pub fn with_nonce_manager(mut self, nonce_manager: Arc<NonceManager>) -> Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    /// Nonce to sign an order with, from the nonce manager if one is attached
+    fn order_nonce(&self, extras: &ExtraOrderArgs) -> Result<U256> {
+        let Some(manager) = &self.nonce_manager else {
+            return Ok(extras.nonce);
+        };
+        let current = manager.current();
+        if !extras.nonce.is_zero() && extras.nonce != current {
+            return Err(Error::InvalidOrder(format!(
+                "Order nonce {} does not match the current nonce {}",
+                extras.nonce, current
+            )));
         }
+        Ok(current)
     }
 
     /// Get the signature type as u8
@@ -58,6 +187,27 @@ impl OrderBuilder {
         self.sig_type.to_u8()
     }
 
+    /// Address that makes the orders on `chain_id`: the funder
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] for an EOA funder other than the
+    /// signer, which the exchange would refuse, and [`Error::Config`] if no
+    /// funder was given and the wallet can't be derived on this chain.
+    pub fn maker_address(&self, chain_id: u64) -> Result<Address> {
+        let signer = self.signer.address();
+        match (self.sig_type, self.funder) {
+            (SignatureType::Eoa, Some(funder)) if funder != signer => {
+                Err(Error::InvalidOrder(format!(
+                    "EOA orders are made by the signer {}, not the funder {}; \
+                     use a proxy or Safe signature type for a funder wallet",
+                    signer, funder
+                )))
+            }
+            (_, Some(funder)) => Ok(funder),
+            (sig_type, None) => funder_address(sig_type, signer, chain_id),
+        }
+    }
+
     /// Calculate order amounts for a limit order
     fn get_order_amounts(
         &self,
@@ -120,14 +270,16 @@ impl OrderBuilder {
     /// Create a market order
     ///
     /// Market orders are executed at the best available price by walking the order book.
+    /// `chain_id` selects the network: a [`Network`](crate::config::Network) or its chain ID.
     pub fn create_market_order(
         &self,
-        chain_id: u64,
+        chain_id: impl Into<u64>,
         order_args: &MarketOrderArgs,
         price: Decimal,
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        let chain_id = chain_id.into();
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -163,14 +315,21 @@ impl OrderBuilder {
     /// Create a limit order
     ///
     /// Limit orders are executed at a specific price or better.
+    /// `chain_id` selects the network: a [`Network`](crate::config::Network) or its chain ID.
+    /// A non-zero `expiration` (Unix seconds, see [`expiration_in`]) makes the
+    /// order good-till-date; post it as [`OrderType::Gtd`](crate::OrderType::Gtd).
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] if `expiration` is not in the future.
     pub fn create_order(
         &self,
-        chain_id: u64,
+        chain_id: impl Into<u64>,
         order_args: &OrderArgs,
         expiration: u64,
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        let chain_id = chain_id.into();
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -195,6 +354,7 @@ impl OrderBuilder {
         let exchange_address = Address::from_str(&contract_config.exchange)
             .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
 
+        let expiration = self.order_expiration(expiration)?;
         self.build_signed_order(
             order_args.token_id.clone(),
             order_args.side,
@@ -220,7 +380,9 @@ impl OrderBuilder {
         expiration: u64,
         extras: &ExtraOrderArgs,
     ) -> Result<SignedOrderRequest> {
+        let maker = self.maker_address(chain_id)?;
         let seed = generate_seed()?;
+        let nonce = self.order_nonce(extras)?;
         let taker_address = Address::from_str(&extras.taker)
             .map_err(|e| Error::InvalidParameter(format!("Invalid taker address: {}", e)))?;
 
@@ -229,14 +391,14 @@ impl OrderBuilder {
 
         let order = Order {
             salt: U256::from(seed),
-            maker: self.funder,
+            maker,
             signer: self.signer.address(),
             taker: taker_address,
             tokenId: u256_token_id,
             makerAmount: U256::from(maker_amount),
             takerAmount: U256::from(taker_amount),
             expiration: U256::from(expiration),
-            nonce: extras.nonce,
+            nonce,
             feeRateBps: U256::from(extras.fee_rate_bps),
             side: side.to_u8(),
             signatureType: self.sig_type.to_u8(),
@@ -246,14 +408,14 @@ impl OrderBuilder {
 
         Ok(SignedOrderRequest {
             salt: seed,
-            maker: self.funder.to_checksum(None),
+            maker: maker.to_checksum(None),
             signer: self.signer.address().to_checksum(None),
             taker: taker_address.to_checksum(None),
             token_id,
             maker_amount: maker_amount.to_string(),
             taker_amount: taker_amount.to_string(),
             expiration: expiration.to_string(),
-            nonce: extras.nonce.to_string(),
+            nonce: nonce.to_string(),
             fee_rate_bps: extras.fee_rate_bps.to_string(),
             side: side.as_str().to_string(),
             signature_type: self.sig_type.to_u8(),
@@ -265,6 +427,7 @@ impl OrderBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Network;
     use alloy_signer_local::PrivateKeySigner;
 
     #[test]
@@ -302,4 +465,288 @@ mod tests {
         assert_eq!(maker_amount, 30_000_000);
         assert_eq!(taker_amount, 27_000_000);
     }
+
+    #[test]
+    fn test_nonce_manager_sets_order_nonce() {
+        let manager = Arc::new(NonceManager::new(U256::from(4)));
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_nonce_manager(manager.clone());
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+        let create = |extras: &ExtraOrderArgs| {
+            builder.create_order(Network::PolygonMainnet, &args, 0, extras, options.clone())
+        };
+
+        let order = create(&ExtraOrderArgs::default()).unwrap();
+        assert_eq!(order.nonce, "4");
+
+        manager.increment();
+        assert_eq!(create(&ExtraOrderArgs::default()).unwrap().nonce, "5");
+        assert_eq!(
+            create(&ExtraOrderArgs::new().nonce(U256::from(5)))
+                .unwrap()
+                .nonce,
+            "5"
+        );
+        assert!(matches!(
+            create(&ExtraOrderArgs::new().nonce(U256::from(4))),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_signed_order_includes_expiration() {
+        use crate::signing::order_domain;
+        use alloy_primitives::PrimitiveSignature;
+        use alloy_sol_types::SolStruct;
+
+        let signer = PrivateKeySigner::random();
+        let address = signer.address();
+        let builder = OrderBuilder::new(signer, None, None);
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+
+        let expiration = expiration_in(Duration::from_secs(3600)).unwrap();
+        let signed = builder
+            .create_order(
+                Network::PolygonMainnet,
+                &args,
+                expiration,
+                &ExtraOrderArgs::default(),
+                options,
+            )
+            .unwrap();
+        assert_eq!(signed.expiration, expiration.to_string());
+
+        // The signature only recovers the signer with the expiration in the struct
+        let exchange =
+            Address::from_str(&get_contract_config(137, false).unwrap().exchange).unwrap();
+        let signature = alloy_primitives::hex::decode(&signed.signature).unwrap();
+        let signature = PrimitiveSignature::try_from(signature.as_slice()).unwrap();
+        let recover = |expiration: u64| {
+            let order = Order {
+                salt: U256::from(signed.salt),
+                maker: address,
+                signer: address,
+                taker: Address::ZERO,
+                tokenId: U256::from(123),
+                makerAmount: U256::from_str(&signed.maker_amount).unwrap(),
+                takerAmount: U256::from_str(&signed.taker_amount).unwrap(),
+                expiration: U256::from(expiration),
+                nonce: U256::ZERO,
+                feeRateBps: U256::ZERO,
+                side: Side::Buy.to_u8(),
+                signatureType: SignatureType::Eoa.to_u8(),
+            };
+            let hash = order.eip712_signing_hash(&order_domain(137, exchange));
+            signature.recover_address_from_prehash(&hash).unwrap()
+        };
+        assert_eq!(recover(expiration), address);
+        assert_ne!(recover(0), address);
+    }
+
+    #[test]
+    fn test_signed_payload_per_signature_type() {
+        use crate::signing::order_domain;
+        use alloy_primitives::{address, PrimitiveSignature};
+        use alloy_sol_types::SolStruct;
+
+        // Well-known development key of 0xf39F...2266, whose wallets are
+        // pinned in `signing::wallet`
+        let signer = PrivateKeySigner::from_str(
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+        )
+        .unwrap();
+        let address = signer.address();
+        assert_eq!(
+            address,
+            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266")
+        );
+        let safe = Address::repeat_byte(0x5a);
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+        let exchange =
+            Address::from_str(&get_contract_config(137, false).unwrap().exchange).unwrap();
+
+        let cases = [
+            (SignatureType::Eoa, None, address),
+            (
+                SignatureType::PolyProxy,
+                None,
+                address!("365f0CA36Ae1f641E02fE3B7743673da42A13A70"),
+            ),
+            (
+                SignatureType::PolyGnosisSafe,
+                None,
+                address!("d93B25cb943D14d0d34FBaF01Fc93a0f8b5F6E47"),
+            ),
+            (SignatureType::PolyGnosisSafe, Some(safe), safe),
+        ];
+        for (sig_type, funder, maker) in cases {
+            let builder = OrderBuilder::new(signer.clone(), Some(sig_type), funder);
+            let signed = builder
+                .create_order(
+                    Network::PolygonMainnet,
+                    &args,
+                    0,
+                    &ExtraOrderArgs::default(),
+                    options.clone(),
+                )
+                .unwrap();
+            assert_eq!(signed.signature_type, sig_type.to_u8());
+            assert_eq!(signed.maker, maker.to_checksum(None));
+            assert_eq!(signed.signer, address.to_checksum(None));
+
+            // The maker and signature type are part of what the signer signed
+            let order = Order {
+                salt: U256::from(signed.salt),
+                maker,
+                signer: address,
+                taker: Address::ZERO,
+                tokenId: U256::from(123),
+                makerAmount: U256::from_str(&signed.maker_amount).unwrap(),
+                takerAmount: U256::from_str(&signed.taker_amount).unwrap(),
+                expiration: U256::ZERO,
+                nonce: U256::ZERO,
+                feeRateBps: U256::ZERO,
+                side: Side::Buy.to_u8(),
+                signatureType: sig_type.to_u8(),
+            };
+            let hash = order.eip712_signing_hash(&order_domain(137, exchange));
+            let signature = alloy_primitives::hex::decode(&signed.signature).unwrap();
+            let signature = PrimitiveSignature::try_from(signature.as_slice()).unwrap();
+            assert_eq!(
+                signature.recover_address_from_prehash(&hash).unwrap(),
+                address
+            );
+        }
+
+        // An EOA can't make orders for another address
+        let builder = OrderBuilder::new(signer, Some(SignatureType::Eoa), Some(safe));
+        assert!(matches!(
+            builder.create_order(
+                Network::PolygonMainnet,
+                &args,
+                0,
+                &ExtraOrderArgs::default(),
+                options
+            ),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_expiration_must_be_in_the_future() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from_str("10").unwrap(),
+            Side::Buy,
+        );
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+        let create = |builder: &OrderBuilder, expiration| {
+            builder.create_order(
+                Network::PolygonMainnet,
+                &args,
+                expiration,
+                &ExtraOrderArgs::default(),
+                options.clone(),
+            )
+        };
+
+        let past = get_current_unix_time_secs().unwrap() - 1;
+        assert!(matches!(
+            create(&builder, past),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert_eq!(create(&builder, 0).unwrap().expiration, "0");
+
+        // A default lifetime applies to orders without an explicit expiration
+        let builder = builder.with_expiration(Duration::from_secs(600));
+        let expiration: u64 = create(&builder, 0).unwrap().expiration.parse().unwrap();
+        assert!(expiration > get_current_unix_time_secs().unwrap());
+    }
+
+    #[test]
+    fn test_resolve_order_type() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::from_str("0.01").unwrap())
+            .neg_risk(false);
+        let order = |price: &str, size: &str, expiration: u64| {
+            let args = OrderArgs::new(
+                "123",
+                Decimal::from_str(price).unwrap(),
+                Decimal::from_str(size).unwrap(),
+                Side::Buy,
+            );
+            builder
+                .create_order(
+                    Network::PolygonMainnet,
+                    &args,
+                    expiration,
+                    &ExtraOrderArgs::default(),
+                    options.clone(),
+                )
+                .unwrap()
+        };
+
+        // 10 shares at 0.50 cost 5 USDC
+        let whole = order("0.5", "10", 0);
+        assert_eq!(
+            resolve_order_type(&whole, OrderType::Fok).unwrap(),
+            OrderType::Fok
+        );
+        assert_eq!(
+            resolve_order_type(&whole, OrderType::Fak).unwrap(),
+            OrderType::Fak
+        );
+        assert!(resolve_order_type(&whole, OrderType::Gtd).is_err());
+
+        // 10.33 shares at 0.57 cost 5.8881 USDC, too precise for FOK/FAK
+        let precise = order("0.57", "10.33", 0);
+        assert_eq!(
+            resolve_order_type(&precise, OrderType::Gtc).unwrap(),
+            OrderType::Gtc
+        );
+        assert!(matches!(
+            resolve_order_type(&precise, OrderType::Fok),
+            Err(Error::InvalidOrder(_))
+        ));
+
+        let expiring = order(
+            "0.5",
+            "10",
+            expiration_in(Duration::from_secs(3600)).unwrap(),
+        );
+        assert_eq!(
+            resolve_order_type(&expiring, OrderType::Gtc).unwrap(),
+            OrderType::Gtd
+        );
+        assert!(resolve_order_type(&expiring, OrderType::Fak).is_err());
+    }
 }
@@ -0,0 +1,264 @@
+use super::rounding::RoundingConfig;
+use crate::error::{Error, Result};
+use crate::types::PriceLevel;
+use crate::Side;
+use rust_decimal::Decimal;
+
+/// Fee rate assumed for a market the API reports no fee rate for
+///
+/// Polymarket markets charge no fees unless they opt in, so the fallback is 0.
+pub const DEFAULT_FEE_RATE_BPS: u32 = 0;
+
+/// Estimated cost of an order, see [`estimate_order_cost`]
+///
+/// All amounts are in USDC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderCostEstimate {
+    /// `price * size`
+    pub notional: Decimal,
+    /// Fee charged by the exchange
+    pub fee: Decimal,
+    /// USDC spent for a buy (`notional + fee`), or received for a sell (`notional - fee`)
+    pub net: Decimal,
+}
+
+/// Estimate the notional, fee and net cost of an order before placing it
+///
+/// Follows the fee formula of the Polymarket exchange contract, which is
+/// symmetric in the price of the two outcomes:
+///
+/// ```text
+/// fee = fee_rate_bps / 10_000 * min(price, 1 - price) * size
+/// ```
+///
+/// Buy fees are collected in outcome tokens and sell fees in USDC; both are
+/// reported here in USDC so they can be compared with the available balance.
+/// The fee is truncated to 6 decimals like on chain, see [`RoundingConfig`].
+/// The fee rate of a market comes from its order book or market data.
+///
+/// # Arguments
+/// * `price` - Limit price, strictly between 0 and 1
+/// * `size` - Number of shares
+/// * `side` - The side of the order
+/// * `fee_rate_bps` - Fee rate in basis points (0 for fee-free markets)
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `price` is outside (0, 1) or `size`
+/// is negative.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::estimate_order_cost;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let estimate = estimate_order_cost(dec!(0.40), dec!(100), Side::Buy, 100).unwrap();
+/// assert_eq!(estimate.notional, dec!(40));
+/// assert_eq!(estimate.fee, dec!(0.4));
+/// assert_eq!(estimate.net, dec!(40.4));
+/// ```
+pub fn estimate_order_cost(
+    price: Decimal,
+    size: Decimal,
+    side: Side,
+    fee_rate_bps: u32,
+) -> Result<OrderCostEstimate> {
+    estimate_order_cost_with_rounding(price, size, side, fee_rate_bps, RoundingConfig::default())
+}
+
+/// Estimate the cost of an order, rounding the fee with `rounding`
+///
+/// Same as [`estimate_order_cost`], which truncates the fee to 6 decimals
+/// like the exchange (see [`RoundingConfig`]).
+pub fn estimate_order_cost_with_rounding(
+    price: Decimal,
+    size: Decimal,
+    side: Side,
+    fee_rate_bps: u32,
+    rounding: RoundingConfig,
+) -> Result<OrderCostEstimate> {
+    if price <= Decimal::ZERO || price >= Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "Price {} must be between 0 and 1 (exclusive)",
+            price
+        )));
+    }
+    if size < Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Size {} must not be negative",
+            size
+        )));
+    }
+
+    let notional = price * size;
+    let fee_rate = Decimal::from(fee_rate_bps) / Decimal::from(10_000);
+    let fee = rounding.round(fee_rate * price.min(Decimal::ONE - price) * size);
+    let net = match side {
+        Side::Buy => notional + fee,
+        Side::Sell => notional - fee,
+    };
+
+    Ok(OrderCostEstimate {
+        notional: notional.normalize(),
+        fee,
+        net: net.normalize(),
+    })
+}
+
+/// Largest buy affordable with a balance, see [`max_buy_size`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxBuySize {
+    /// Number of shares
+    pub size: Decimal,
+    /// USDC spent on those shares, fees included
+    pub cost: Decimal,
+    /// Whether the whole ask book was bought before the balance ran out
+    ///
+    /// When set, `size` is everything the book can fill and some of the
+    /// balance is left over.
+    pub book_exhausted: bool,
+}
+
+/// Largest number of shares a market buy can fill with `balance` USDC
+///
+/// Walks the asks from the lowest price and buys each level until the
+/// balance, including the fee of each fill (see [`estimate_order_cost`]),
+/// runs out. The last level is bought partially, so round the size with
+/// [`round_size`](crate::orders::round_size) before placing the order.
+///
+/// # Arguments
+/// * `asks` - Ask levels, in any order
+/// * `balance` - Available USDC
+/// * `fee_rate_bps` - Fee rate in basis points (0 for fee-free markets)
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `balance` is negative or a level's
+/// price is outside (0, 1).
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::max_buy_size;
+/// use polymarket_rs::types::PriceLevel;
+/// use rust_decimal_macros::dec;
+///
+/// let asks = vec![PriceLevel::new(dec!(0.5), dec!(10)), PriceLevel::new(dec!(0.6), dec!(10))];
+/// let max = max_buy_size(&asks, dec!(8), 0).unwrap();
+/// assert_eq!(max.size, dec!(15));
+/// assert!(!max.book_exhausted);
+/// ```
+pub fn max_buy_size(
+    asks: &[PriceLevel],
+    balance: Decimal,
+    fee_rate_bps: u32,
+) -> Result<MaxBuySize> {
+    if balance < Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Balance {} must not be negative",
+            balance
+        )));
+    }
+
+    let mut levels = asks.to_vec();
+    levels.sort_by_key(|level| level.price);
+
+    let mut remaining = balance;
+    let mut size = Decimal::ZERO;
+    for level in &levels {
+        // USDC per share at this level, fee included
+        let unit_cost =
+            estimate_order_cost(level.price, Decimal::ONE, Side::Buy, fee_rate_bps)?.net;
+        let level_cost = unit_cost * level.size;
+        if level_cost > remaining {
+            size += remaining / unit_cost;
+            return Ok(MaxBuySize {
+                size: size.normalize(),
+                cost: balance.normalize(),
+                book_exhausted: false,
+            });
+        }
+        remaining -= level_cost;
+        size += level.size;
+    }
+
+    Ok(MaxBuySize {
+        size: size.normalize(),
+        cost: (balance - remaining).normalize(),
+        book_exhausted: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_fee_uses_the_cheaper_outcome_price() {
+        // 1% fee: a buy at 0.80 pays the same fee as a buy at 0.20
+        let high = estimate_order_cost(dec!(0.80), dec!(100), Side::Buy, 100).unwrap();
+        let low = estimate_order_cost(dec!(0.20), dec!(100), Side::Buy, 100).unwrap();
+        assert_eq!(high.fee, dec!(0.2));
+        assert_eq!(low.fee, dec!(0.2));
+        assert_eq!(high.net, dec!(80.2));
+        assert_eq!(low.net, dec!(20.2));
+    }
+
+    #[test]
+    fn test_sell_fee_reduces_proceeds() {
+        let estimate = estimate_order_cost(dec!(0.55), dec!(20), Side::Sell, 200).unwrap();
+        assert_eq!(estimate.notional, dec!(11));
+        assert_eq!(estimate.fee, dec!(0.18));
+        assert_eq!(estimate.net, dec!(10.82));
+    }
+
+    #[test]
+    fn test_zero_fee_rate() {
+        let estimate = estimate_order_cost(dec!(0.5), dec!(10), Side::Buy, 0).unwrap();
+        assert_eq!(estimate.fee, Decimal::ZERO);
+        assert_eq!(estimate.net, estimate.notional);
+    }
+
+    fn asks() -> Vec<PriceLevel> {
+        vec![
+            PriceLevel::new(dec!(0.6), dec!(10)),
+            PriceLevel::new(dec!(0.5), dec!(10)),
+        ]
+    }
+
+    #[test]
+    fn test_max_buy_size_stops_inside_a_level() {
+        // 10 shares at 0.50 cost 5, the remaining 3 buy 5 shares at 0.60
+        let max = max_buy_size(&asks(), dec!(8), 0).unwrap();
+        assert_eq!(max.size, dec!(15));
+        assert_eq!(max.cost, dec!(8));
+        assert!(!max.book_exhausted);
+    }
+
+    #[test]
+    fn test_max_buy_size_accounts_for_fees() {
+        // 10% fee: a share at 0.50 costs 0.55, at 0.60 costs 0.64
+        let max = max_buy_size(&asks(), dec!(5.5), 1000).unwrap();
+        assert_eq!(max.size, dec!(10));
+        let max = max_buy_size(&asks(), dec!(8.7), 1000).unwrap();
+        assert_eq!(max.size, dec!(15));
+    }
+
+    #[test]
+    fn test_max_buy_size_exhausts_book() {
+        let max = max_buy_size(&asks(), dec!(100), 0).unwrap();
+        assert_eq!(max.size, dec!(20));
+        assert_eq!(max.cost, dec!(11));
+        assert!(max.book_exhausted);
+
+        let empty = max_buy_size(&[], dec!(100), 0).unwrap();
+        assert_eq!(empty.size, Decimal::ZERO);
+        assert!(empty.book_exhausted);
+        assert!(max_buy_size(&asks(), dec!(-1), 0).is_err());
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        assert!(estimate_order_cost(dec!(1), dec!(10), Side::Buy, 0).is_err());
+        assert!(estimate_order_cost(dec!(0.5), dec!(-1), Side::Buy, 0).is_err());
+    }
+}
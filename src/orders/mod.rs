@@ -1,7 +1,26 @@
+mod book_hash;
 mod builder;
+mod fees;
 mod price;
 mod rounding;
+mod twap;
 
-pub use builder::OrderBuilder;
-pub use price::calculate_market_price;
-pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
+pub use book_hash::compute_book_hash;
+pub use builder::{expiration_in, resolve_order_type, OrderBuilder};
+pub use fees::{
+    estimate_order_cost, estimate_order_cost_with_rounding, max_buy_size, MaxBuySize,
+    OrderCostEstimate, DEFAULT_FEE_RATE_BPS,
+};
+pub use price::{
+    best_ask, best_bid, calculate_average_fill_price, calculate_average_fill_price_with_rounding,
+    calculate_market_fill, calculate_market_price, calculate_market_price_for_shares,
+    calculate_market_price_with_slippage, calculate_market_price_with_validation, complement_price,
+    implied_no_price, is_crossing, liquidity_within_bps, mid_price, price_impact, spread,
+    BookValidation, MarketFill,
+};
+pub use rounding::{
+    decimal_to_token_u64, fix_amount_rounding, price_to_string, round_size, round_to_tick,
+    round_to_tick_taker, size_to_string, RoundConfig, RoundingConfig, ROUNDING_CONFIG,
+    TOKEN_DECIMALS,
+};
+pub use twap::twap_slices;
@@ -1,5 +1,6 @@
+use super::rounding::RoundingConfig;
 use crate::error::{Error, Result};
-use crate::types::PriceLevel;
+use crate::types::{OrderBookSummary, PriceLevel};
 use crate::Side;
 use rust_decimal::Decimal;
 
@@ -11,9 +12,18 @@ use rust_decimal::Decimal;
 /// # Arguments
 /// * `positions` - The order book positions to walk through
 /// * `shares_to_match` - The number of shares to match
+/// * `side` - The side of the market order
+///
+/// Buys consume asks from lowest to highest price and sells consume bids from
+/// highest to lowest. `positions` is sorted internally, so it may be passed in
+/// any order, and levels repeated at the same price are merged. For both sides `shares_to_match` counts shares, not dollars;
+/// to walk the book by USDC notional use [`calculate_market_fill`].
 ///
 /// # Returns
-/// The weighted average price at which the market order can be filled, or an error if there's insufficient liquidity
+/// The weighted average price at which the market order can be filled, or an error if there's insufficient liquidity.
+/// The price is truncated to 6 decimals, see [`RoundingConfig`].
+/// An empty `positions` slice fails with `Error::InvalidOrder("order book is empty")`.
+/// A zero or negative `shares_to_match` fails with `Error::InvalidParameter`.
 ///
 /// # Example
 /// ```no_run
@@ -33,31 +43,181 @@ pub fn calculate_market_price(
     shares_to_match: Decimal,
     side: Side,
 ) -> Result<Decimal> {
+    calculate_market_price_with_validation(
+        positions,
+        shares_to_match,
+        side,
+        BookValidation::Lenient,
+    )
+}
+
+/// How strictly book levels are checked before walking them
+///
+/// Zero-size levels are stale entries and are always skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BookValidation {
+    /// Use every price as-is
+    #[default]
+    Lenient,
+    /// Reject any level priced outside (0, 1) with [`Error::InvalidOrder`]
+    Strict,
+}
+
+/// Calculate the market price, checking the book levels first
+///
+/// Same as [`calculate_market_price`], which uses
+/// [`BookValidation::Lenient`]. Use [`BookValidation::Strict`] to fail on
+/// implausible prices instead of letting them skew the fill.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::{calculate_market_price_with_validation, BookValidation};
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::Decimal;
+///
+/// let asks = vec![
+///     PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) },
+///     PriceLevel { price: Decimal::new(150, 2), size: Decimal::new(100, 0) },
+/// ];
+/// let result = calculate_market_price_with_validation(
+///     &asks,
+///     Decimal::new(10, 0),
+///     Side::Buy,
+///     BookValidation::Strict,
+/// );
+/// assert!(result.is_err());
+/// ```
+pub fn calculate_market_price_with_validation(
+    positions: &[PriceLevel],
+    shares_to_match: Decimal,
+    side: Side,
+    validation: BookValidation,
+) -> Result<Decimal> {
+    if shares_to_match <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Amount to match must be positive, got {}",
+            shares_to_match
+        )));
+    }
+    ensure_not_empty(positions)?;
+    validate_levels(positions, validation)?;
     let mut remaining = shares_to_match;
     let mut total_cost = Decimal::ZERO;
 
-    // If buying, walk the asks (lowest to highest)
-    // If selling, walk the bids (highest to lowest)
-    let positions = match side {
-        Side::Buy => {
-            let mut asks = positions.to_vec();
-            asks.sort_by(|a, b| a.price.cmp(&b.price));
-            asks
-        }
-        Side::Sell => {
-            let mut bids = positions.to_vec();
-            bids.sort_by(|a, b| b.price.cmp(&a.price));
-            bids
+    for p in sorted_levels(positions, side) {
+        let filled = remaining.min(p.size);
+        total_cost += filled * p.price;
+        remaining -= filled;
+
+        if remaining.is_zero() {
+            // weighted avg price
+            return Ok(RoundingConfig::default().round(total_cost / shares_to_match));
         }
+    }
+
+    Err(Error::InvalidOrder(format!(
+        "Not enough liquidity to create market order with amount {}",
+        shares_to_match
+    )))
+}
+
+/// Weighted average price to fill a target number of shares
+///
+/// Same as [`calculate_market_price`], named for its units: `shares` is a
+/// share count for both sides, never USDC notional. The last level reached
+/// may be only partially consumed, e.g. buying 150 shares against asks of
+/// 100 @ 0.50 and 200 @ 0.51 takes 50 shares of the second level for an
+/// average of 0.5033...
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `shares` - Number of shares to buy or sell
+/// * `side` - Buy walks asks from lowest to highest, Sell walks bids from highest to lowest
+pub fn calculate_market_price_for_shares(
+    positions: &[PriceLevel],
+    shares: Decimal,
+    side: Side,
+) -> Result<Decimal> {
+    calculate_market_price(positions, shares, side)
+}
+
+/// Calculate the market price, rejecting fills that walk too far into the book
+///
+/// Walks the book like [`calculate_market_price`], but fails if the price of
+/// the last level consumed deviates from the top-of-book price by more than
+/// `max_slippage_bps` basis points.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `shares_to_match` - The number of shares to match
+/// * `side` - Buy walks asks from lowest to highest, Sell walks bids from highest to lowest
+/// * `max_slippage_bps` - Maximum allowed deviation from the best price, in basis points
+///
+/// # Returns
+/// The weighted average price, [`Error::SlippageExceeded`] if the book is too
+/// thin, [`Error::InvalidOrder`] if there's insufficient liquidity, or
+/// [`Error::InvalidParameter`] if `shares_to_match` is not positive
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_market_price_with_slippage;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::Decimal;
+///
+/// let asks = vec![
+///     PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) },
+///     PriceLevel { price: Decimal::new(60, 2), size: Decimal::new(100, 0) },
+/// ];
+/// // Reaching the 0.60 level is 2000 bps away from 0.50
+/// let result = calculate_market_price_with_slippage(&asks, Decimal::new(150, 0), Side::Buy, 500);
+/// assert!(result.is_err());
+/// ```
+pub fn calculate_market_price_with_slippage(
+    positions: &[PriceLevel],
+    shares_to_match: Decimal,
+    side: Side,
+    max_slippage_bps: u32,
+) -> Result<Decimal> {
+    if shares_to_match <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Amount to match must be positive, got {}",
+            shares_to_match
+        )));
+    }
+    ensure_not_empty(positions)?;
+    let levels = sorted_levels(positions, side);
+    let mut remaining = shares_to_match;
+    let mut total_cost = Decimal::ZERO;
+
+    let Some(best_price) = levels.first().map(|level| level.price) else {
+        return Err(Error::InvalidOrder(format!(
+            "Not enough liquidity to create market order with amount {}",
+            shares_to_match
+        )));
     };
+    let max_deviation = best_price * Decimal::from(max_slippage_bps) / Decimal::from(10_000);
+
+    for p in levels {
+        let deviation = match side {
+            Side::Buy => p.price - best_price,
+            Side::Sell => best_price - p.price,
+        };
+        if deviation > max_deviation {
+            return Err(Error::SlippageExceeded {
+                best_price,
+                execution_price: p.price,
+                max_slippage_bps,
+            });
+        }
 
-    for p in positions {
         let filled = remaining.min(p.size);
         total_cost += filled * p.price;
         remaining -= filled;
 
         if remaining.is_zero() {
-            return Ok(total_cost / shares_to_match); // weighted avg price
+            return Ok(RoundingConfig::default().round(total_cost / shares_to_match));
         }
     }
 
@@ -67,13 +227,443 @@ pub fn calculate_market_price(
     )))
 }
 
+/// Calculate the size-weighted average fill price and the total size filled
+///
+/// Walks the book like [`calculate_market_price`], consuming each level in
+/// turn and only the needed part of the last one.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `amount_to_match` - The number of shares to match
+/// * `side` - Buy walks asks from lowest to highest, Sell walks bids from highest to lowest
+///
+/// # Returns
+/// A tuple of `(average_price, size_filled)`, or an error if there's insufficient liquidity
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_average_fill_price;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::Decimal;
+///
+/// let positions = vec![
+///     PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) },
+///     PriceLevel { price: Decimal::new(60, 2), size: Decimal::new(100, 0) },
+/// ];
+/// let (price, size) =
+///     calculate_average_fill_price(&positions, Decimal::new(150, 0), Side::Buy).unwrap();
+/// assert_eq!(size, Decimal::new(150, 0));
+/// ```
+pub fn calculate_average_fill_price(
+    positions: &[PriceLevel],
+    amount_to_match: Decimal,
+    side: Side,
+) -> Result<(Decimal, Decimal)> {
+    calculate_average_fill_price_with_rounding(
+        positions,
+        amount_to_match,
+        side,
+        RoundingConfig::default(),
+    )
+}
+
+/// Calculate the average fill price, rounded with `rounding`
+///
+/// Same as [`calculate_average_fill_price`], which truncates the price to 6
+/// decimals (see [`RoundingConfig`]).
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::{calculate_average_fill_price_with_rounding, RoundingConfig};
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::{Decimal, RoundingStrategy};
+///
+/// let asks = vec![
+///     PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) },
+///     PriceLevel { price: Decimal::new(51, 2), size: Decimal::new(50, 0) },
+/// ];
+/// let rounding = RoundingConfig::new(RoundingStrategy::MidpointNearestEven, 4);
+/// let (price, _) =
+///     calculate_average_fill_price_with_rounding(&asks, Decimal::new(150, 0), Side::Buy, rounding)
+///         .unwrap();
+/// assert_eq!(price, Decimal::new(5033, 4));
+/// ```
+pub fn calculate_average_fill_price_with_rounding(
+    positions: &[PriceLevel],
+    amount_to_match: Decimal,
+    side: Side,
+    rounding: RoundingConfig,
+) -> Result<(Decimal, Decimal)> {
+    if amount_to_match <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Amount to match must be positive, got {}",
+            amount_to_match
+        )));
+    }
+    ensure_not_empty(positions)?;
+
+    let mut filled_size = Decimal::ZERO;
+    let mut total_cost = Decimal::ZERO;
+
+    for p in sorted_levels(positions, side) {
+        let filled = (amount_to_match - filled_size).min(p.size);
+        total_cost += filled * p.price;
+        filled_size += filled;
+
+        if filled_size == amount_to_match {
+            return Ok((rounding.round(total_cost / filled_size), filled_size));
+        }
+    }
+
+    Err(Error::InvalidOrder(format!(
+        "Not enough liquidity to create market order with amount {}",
+        amount_to_match
+    )))
+}
+
+/// Price impact of a market order, in basis points
+///
+/// Walks the book like [`calculate_average_fill_price`] and returns how far
+/// the average fill price is from the best price, relative to the best
+/// price. The impact is never negative: buys fill at or above the best ask
+/// and sells at or below the best bid.
+///
+/// # Arguments
+/// * `positions` - The order book side to consume (asks for Buy, bids for Sell)
+/// * `size` - Number of shares to buy or sell
+/// * `side` - Buy walks asks from lowest to highest, Sell walks bids from highest to lowest
+///
+/// # Returns
+/// The impact in basis points, or [`Error::InvalidOrder`] if the book can't
+/// fill `size`
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::price_impact;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::Decimal;
+///
+/// let asks = vec![
+///     PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) },
+///     PriceLevel { price: Decimal::new(60, 2), size: Decimal::new(100, 0) },
+/// ];
+/// // Average 0.55 against a best ask of 0.50
+/// let impact = price_impact(&asks, Decimal::new(200, 0), Side::Buy).unwrap();
+/// assert_eq!(impact, Decimal::new(1000, 0));
+/// ```
+pub fn price_impact(positions: &[PriceLevel], size: Decimal, side: Side) -> Result<Decimal> {
+    let (average_price, _) = calculate_average_fill_price(positions, size, side)?;
+    let best_price = sorted_levels(positions, side)
+        .first()
+        .map(|level| level.price)
+        .filter(|price| !price.is_zero())
+        .ok_or_else(|| Error::InvalidOrder("order book has no priced levels".to_string()))?;
+
+    let impact = (average_price - best_price).abs() / best_price * Decimal::from(10_000);
+    Ok(impact.normalize())
+}
+
+/// Result of walking the book for a market order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketFill {
+    /// Size-weighted average execution price
+    pub price: Decimal,
+    /// Total shares bought or sold
+    pub filled_size: Decimal,
+    /// Portion of the requested amount actually used (USDC for buys, shares for sells)
+    pub consumed_amount: Decimal,
+}
+
+/// Walk the book for a market order and report what it would fill
+///
+/// Uses the same amount semantics as market order creation: for buys
+/// `amount_to_match` is the USDC to spend, for sells it is the number of
+/// shares to sell. The last level reached may be only partially consumed.
+///
+/// Unlike [`calculate_market_price`], a book too thin for the whole amount
+/// is not an error: the returned `consumed_amount` is then less than
+/// `amount_to_match`.
+///
+/// # Arguments
+/// * `positions` - The order book positions to walk through
+/// * `amount_to_match` - USDC to spend (Buy) or shares to sell (Sell)
+/// * `side` - Buy walks asks from lowest to highest, Sell walks bids from highest to lowest
+///
+/// # Returns
+/// The resulting [`MarketFill`], or an error if nothing can be filled
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::calculate_market_fill;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::Side;
+/// use rust_decimal::Decimal;
+///
+/// let asks = vec![PriceLevel { price: Decimal::new(50, 2), size: Decimal::new(100, 0) }];
+/// // Spend $20 at 0.50
+/// let fill = calculate_market_fill(&asks, Decimal::new(20, 0), Side::Buy).unwrap();
+/// assert_eq!(fill.filled_size, Decimal::new(40, 0));
+/// ```
+pub fn calculate_market_fill(
+    positions: &[PriceLevel],
+    amount_to_match: Decimal,
+    side: Side,
+) -> Result<MarketFill> {
+    if amount_to_match <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Amount to match must be positive, got {}",
+            amount_to_match
+        )));
+    }
+    ensure_not_empty(positions)?;
+
+    let mut consumed = Decimal::ZERO;
+    let mut filled_size = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+
+    for p in sorted_levels(positions, side) {
+        if p.price <= Decimal::ZERO || p.size <= Decimal::ZERO {
+            continue;
+        }
+
+        let remaining = amount_to_match - consumed;
+        let (shares, cost) = match side {
+            Side::Buy => {
+                let cost = remaining.min(p.size * p.price);
+                (cost / p.price, cost)
+            }
+            Side::Sell => {
+                let shares = remaining.min(p.size);
+                (shares, shares * p.price)
+            }
+        };
+
+        filled_size += shares;
+        notional += cost;
+        consumed += match side {
+            Side::Buy => cost,
+            Side::Sell => shares,
+        };
+
+        if consumed == amount_to_match {
+            break;
+        }
+    }
+
+    if filled_size.is_zero() {
+        return Err(Error::InvalidOrder(format!(
+            "No liquidity to fill market order with amount {}",
+            amount_to_match
+        )));
+    }
+
+    Ok(MarketFill {
+        price: RoundingConfig::default().round(notional / filled_size),
+        filled_size,
+        consumed_amount: consumed,
+    })
+}
+
+/// Highest bid price, ignoring empty levels
+///
+/// `bids` may be passed in any order. Returns `None` if there are no bids.
+pub fn best_bid(bids: &[PriceLevel]) -> Option<Decimal> {
+    bids.iter()
+        .filter(|level| level.size > Decimal::ZERO)
+        .map(|level| level.price)
+        .max()
+}
+
+/// Lowest ask price, ignoring empty levels
+///
+/// `asks` may be passed in any order. Returns `None` if there are no asks.
+pub fn best_ask(asks: &[PriceLevel]) -> Option<Decimal> {
+    asks.iter()
+        .filter(|level| level.size > Decimal::ZERO)
+        .map(|level| level.price)
+        .min()
+}
+
+/// Midpoint between the best bid and best ask
+///
+/// Returns `None` if either side of the book is empty.
+pub fn mid_price(bids: &[PriceLevel], asks: &[PriceLevel]) -> Option<Decimal> {
+    Some((best_bid(bids)? + best_ask(asks)?) / Decimal::TWO)
+}
+
+/// Difference between the best ask and best bid
+///
+/// Returns `None` if either side of the book is empty.
+pub fn spread(bids: &[PriceLevel], asks: &[PriceLevel]) -> Option<Decimal> {
+    Some(best_ask(asks)? - best_bid(bids)?)
+}
+
+/// Whether a limit order at `price` would cross the spread and trade immediately
+///
+/// A buy at or above the best ask, or a sell at or below the best bid, takes
+/// liquidity (and pays taker fees) instead of resting on the book. Check this
+/// before posting an order that is meant to be a maker quote. A missing side
+/// of the book can't be crossed.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::is_crossing;
+/// use polymarket_rs::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let (bid, ask) = (Some(dec!(0.48)), Some(dec!(0.52)));
+/// assert!(!is_crossing(dec!(0.50), Side::Buy, bid, ask));
+/// assert!(is_crossing(dec!(0.52), Side::Buy, bid, ask));
+/// ```
+pub fn is_crossing(
+    price: Decimal,
+    side: Side,
+    best_bid: Option<Decimal>,
+    best_ask: Option<Decimal>,
+) -> bool {
+    match side {
+        Side::Buy => best_ask.is_some_and(|ask| price >= ask),
+        Side::Sell => best_bid.is_some_and(|bid| price <= bid),
+    }
+}
+
+/// Price of the complementary outcome in a binary market, `1 - price`
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] unless `price` is strictly between 0 and 1.
+pub fn complement_price(price: Decimal) -> Result<Decimal> {
+    if price <= Decimal::ZERO || price >= Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "Price {} must be between 0 and 1 (exclusive)",
+            price
+        )));
+    }
+    Ok(Decimal::ONE - price)
+}
+
+/// NO price implied by the YES token's order book
+///
+/// The complement of the YES midpoint, useful when the NO book is too thin
+/// to price on its own.
+///
+/// # Errors
+/// Returns [`Error::InvalidOrder`] if either side of `yes_book` is empty, or
+/// [`Error::InvalidParameter`] if the midpoint is not strictly between 0 and 1.
+pub fn implied_no_price(yes_book: &OrderBookSummary) -> Result<Decimal> {
+    let mid = mid_price(&yes_book.bids, &yes_book.asks).ok_or_else(|| {
+        Error::InvalidOrder("Order book needs both bids and asks to imply a price".to_string())
+    })?;
+    complement_price(mid)
+}
+
+/// Total size on each side of the book within `bps` basis points of the mid
+///
+/// A level counts if `|price - mid| <= mid * bps / 10_000`. The mid is the
+/// midpoint of the best bid and best ask; if one side is empty the best price
+/// of the other side is used instead, so only that side can report liquidity.
+///
+/// # Returns
+/// `(bid_size, ask_size)`, both zero for an empty book
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::liquidity_within_bps;
+/// use polymarket_rs::types::PriceLevel;
+/// use rust_decimal::Decimal;
+///
+/// let bids = vec![PriceLevel { price: Decimal::new(49, 2), size: Decimal::new(100, 0) }];
+/// let asks = vec![PriceLevel { price: Decimal::new(51, 2), size: Decimal::new(50, 0) }];
+///
+/// // Mid is 0.50, so a 500 bps band covers 0.475..=0.525
+/// let (bid_size, ask_size) = liquidity_within_bps(&bids, &asks, 500);
+/// assert_eq!(bid_size, Decimal::new(100, 0));
+/// assert_eq!(ask_size, Decimal::new(50, 0));
+/// ```
+pub fn liquidity_within_bps(
+    bids: &[PriceLevel],
+    asks: &[PriceLevel],
+    bps: u32,
+) -> (Decimal, Decimal) {
+    let mid = match (best_bid(bids), best_ask(asks)) {
+        (Some(bid), Some(ask)) => (bid + ask) / Decimal::TWO,
+        (Some(price), None) | (None, Some(price)) => price,
+        (None, None) => return (Decimal::ZERO, Decimal::ZERO),
+    };
+    let band = mid * Decimal::from(bps) / Decimal::from(10_000);
+
+    let size_within = |levels: &[PriceLevel]| {
+        levels
+            .iter()
+            .filter(|level| (level.price - mid).abs() <= band)
+            .map(|level| level.size)
+            .sum::<Decimal>()
+    };
+
+    (size_within(bids), size_within(asks))
+}
+
+/// Order levels in the direction a market order consumes them
+///
+/// If buying, walk the asks (lowest to highest).
+/// If selling, walk the bids (highest to lowest).
+/// Levels repeated at the same price are merged by summing their sizes, and
+/// levels without size are dropped.
+fn sorted_levels(positions: &[PriceLevel], side: Side) -> Vec<PriceLevel> {
+    let mut levels: Vec<PriceLevel> = positions
+        .iter()
+        .filter(|level| level.size > Decimal::ZERO)
+        .cloned()
+        .collect();
+    match side {
+        Side::Buy => levels.sort_by_key(|level| level.price),
+        Side::Sell => levels.sort_by_key(|level| std::cmp::Reverse(level.price)),
+    }
+    levels.dedup_by(|level, kept| {
+        let duplicate = level.price == kept.price;
+        if duplicate {
+            kept.size += level.size;
+        }
+        duplicate
+    });
+    levels
+}
+
+/// Check the priced levels of a book against `validation`
+fn validate_levels(positions: &[PriceLevel], validation: BookValidation) -> Result<()> {
+    if validation == BookValidation::Strict {
+        let invalid = positions.iter().find(|level| {
+            level.size > Decimal::ZERO
+                && (level.price <= Decimal::ZERO || level.price >= Decimal::ONE)
+        });
+        if let Some(level) = invalid {
+            return Err(Error::InvalidOrder(format!(
+                "Book level price {} is outside (0, 1)",
+                level.price
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reject an empty book, which usually means the fetch went wrong rather
+/// than that the market is too thin
+fn ensure_not_empty(positions: &[PriceLevel]) -> Result<()> {
+    if positions.is_empty() {
+        return Err(Error::InvalidOrder("order book is empty".to_string()));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
     fn order(price: Decimal, size: Decimal) -> PriceLevel {
-        PriceLevel { price, size }
+        PriceLevel::new(price, size)
     }
 
     #[test]
@@ -109,4 +699,316 @@ mod tests {
         let result = calculate_market_price(&positions, dec!(20), Side::Buy);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_market_price_rejects_non_positive_shares() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+
+        for shares in [dec!(0), dec!(-5)] {
+            let result = calculate_market_price(&positions, shares, Side::Buy);
+            assert!(matches!(result, Err(Error::InvalidParameter(_))));
+
+            let result = calculate_market_price_with_slippage(&positions, shares, Side::Buy, 100);
+            assert!(matches!(result, Err(Error::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn test_average_fill_price_partial_last_level() {
+        // 10 @ 0.50 fully consumed, 5 of 20 @ 0.60 consumed
+        let positions = vec![order(dec!(0.60), dec!(20)), order(dec!(0.50), dec!(10))];
+
+        // (10*0.50 + 5*0.60) / 15 = 8 / 15, truncated to 6 decimals
+        let (price, size) = calculate_average_fill_price(&positions, dec!(15), Side::Buy).unwrap();
+        assert_eq!(size, dec!(15));
+        assert_eq!(price, dec!(0.533333));
+    }
+
+    #[test]
+    fn test_average_fill_price_insufficient_liquidity() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        let result = calculate_average_fill_price(&positions, dec!(20), Side::Sell);
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_market_fill_buy_spends_usdc() {
+        // $5 buys 10 @ 0.50, then $3 buys 5 of 20 @ 0.60
+        let asks = vec![order(dec!(0.50), dec!(10)), order(dec!(0.60), dec!(20))];
+
+        let fill = calculate_market_fill(&asks, dec!(8), Side::Buy).unwrap();
+        assert_eq!(fill.filled_size, dec!(15));
+        assert_eq!(fill.consumed_amount, dec!(8));
+        assert_eq!(fill.price, dec!(0.533333));
+    }
+
+    #[test]
+    fn test_market_fill_sell_partial_book() {
+        let bids = vec![order(dec!(0.40), dec!(10)), order(dec!(0.45), dec!(10))];
+
+        // Only 20 shares of bids for 30 requested
+        let fill = calculate_market_fill(&bids, dec!(30), Side::Sell).unwrap();
+        assert_eq!(fill.filled_size, dec!(20));
+        assert_eq!(fill.consumed_amount, dec!(20));
+        assert_eq!(fill.price, dec!(0.425));
+    }
+
+    #[test]
+    fn test_market_fill_empty_book() {
+        let result = calculate_market_fill(&[], dec!(10), Side::Buy);
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_empty_book_is_distinct_from_thin_book() {
+        fn empty(result: Result<Decimal>) -> bool {
+            match result {
+                Err(Error::InvalidOrder(msg)) => msg == "order book is empty",
+                _ => false,
+            }
+        }
+
+        assert!(empty(calculate_market_price(&[], dec!(10), Side::Buy)));
+        assert!(empty(calculate_market_price_with_slippage(
+            &[],
+            dec!(10),
+            Side::Sell,
+            100
+        )));
+        assert!(empty(
+            calculate_average_fill_price(&[], dec!(10), Side::Buy).map(|(price, _)| price)
+        ));
+
+        let thin = vec![order(dec!(0.50), dec!(1))];
+        assert!(!empty(calculate_market_price(&thin, dec!(10), Side::Buy)));
+        assert!(calculate_market_price(&thin, dec!(10), Side::Buy).is_err());
+    }
+
+    #[test]
+    fn test_slippage_within_limit() {
+        let asks = vec![order(dec!(0.50), dec!(10)), order(dec!(0.51), dec!(20))];
+
+        // 0.51 is 200 bps above 0.50
+        let price = calculate_market_price_with_slippage(&asks, dec!(20), Side::Buy, 200).unwrap();
+        assert_eq!(price, dec!(0.505));
+    }
+
+    #[test]
+    fn test_slippage_exceeded_reports_prices() {
+        let bids = vec![order(dec!(0.50), dec!(10)), order(dec!(0.40), dec!(20))];
+
+        let err =
+            calculate_market_price_with_slippage(&bids, dec!(20), Side::Sell, 100).unwrap_err();
+        match err {
+            Error::SlippageExceeded {
+                best_price,
+                execution_price,
+                max_slippage_bps,
+            } => {
+                assert_eq!(best_price, dec!(0.50));
+                assert_eq!(execution_price, dec!(0.40));
+                assert_eq!(max_slippage_bps, 100);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_slippage_not_checked_for_unused_levels() {
+        let asks = vec![order(dec!(0.50), dec!(10)), order(dec!(0.90), dec!(20))];
+        let price = calculate_market_price_with_slippage(&asks, dec!(10), Side::Buy, 0).unwrap();
+        assert_eq!(price, dec!(0.50));
+    }
+
+    #[test]
+    fn test_unsorted_positions() {
+        // Bids arrive in ascending order from the API; sells must start at 0.55
+        let bids = vec![
+            order(dec!(0.45), dec!(10)),
+            order(dec!(0.55), dec!(10)),
+            order(dec!(0.50), dec!(10)),
+        ];
+
+        let price = calculate_market_price(&bids, dec!(20), Side::Sell).unwrap();
+        assert_eq!(price, dec!(0.525));
+
+        let price = calculate_market_price(&bids, dec!(20), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.475));
+    }
+
+    #[test]
+    fn test_shuffled_duplicate_levels() {
+        // 0.50 appears twice, e.g. after merging a snapshot with a delta
+        let asks = vec![
+            order(dec!(0.52), dec!(10)),
+            order(dec!(0.50), dec!(4)),
+            order(dec!(0.51), dec!(10)),
+            order(dec!(0.50), dec!(6)),
+        ];
+
+        let levels = sorted_levels(&asks, Side::Buy);
+        assert_eq!(levels.len(), 3);
+        assert_eq!((levels[0].price, levels[0].size), (dec!(0.50), dec!(10)));
+
+        let price = calculate_market_price(&asks, dec!(20), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.505));
+        let price = calculate_market_price(&asks, dec!(10), Side::Sell).unwrap();
+        assert_eq!(price, dec!(0.52));
+    }
+
+    #[test]
+    fn test_zero_size_and_out_of_range_levels() {
+        let asks = vec![
+            order(dec!(0.40), dec!(0)),
+            order(dec!(0.50), dec!(10)),
+            order(dec!(1.50), dec!(10)),
+        ];
+
+        // The stale 0.40 level is skipped rather than becoming the best price
+        let price = calculate_market_price_with_slippage(&asks, dec!(10), Side::Buy, 0).unwrap();
+        assert_eq!(price, dec!(0.50));
+
+        let price = calculate_market_price(&asks, dec!(20), Side::Buy).unwrap();
+        assert_eq!(price, dec!(1.00));
+        let result = calculate_market_price_with_validation(
+            &asks,
+            dec!(20),
+            Side::Buy,
+            BookValidation::Strict,
+        );
+        assert!(matches!(result, Err(Error::InvalidOrder(msg)) if msg.contains("1.50")));
+
+        // Only zero-size levels: too thin, not empty
+        let stale = vec![order(dec!(0.40), dec!(0))];
+        let result = calculate_market_price(&stale, dec!(1), Side::Buy);
+        assert!(matches!(result, Err(Error::InvalidOrder(msg)) if msg.contains("liquidity")));
+    }
+
+    #[test]
+    fn test_price_impact() {
+        let bids = vec![order(dec!(0.40), dec!(50)), order(dec!(0.50), dec!(50))];
+
+        // Filling within the best level has no impact
+        assert_eq!(price_impact(&bids, dec!(50), Side::Sell).unwrap(), dec!(0));
+        // Average 0.45 against a best bid of 0.50
+        assert_eq!(
+            price_impact(&bids, dec!(100), Side::Sell).unwrap(),
+            dec!(1000)
+        );
+
+        let result = price_impact(&bids, dec!(101), Side::Sell);
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_top_of_book() {
+        let bids = vec![
+            order(dec!(0.48), dec!(10)),
+            order(dec!(0.50), dec!(5)),
+            order(dec!(0.51), dec!(0)),
+        ];
+        let asks = vec![order(dec!(0.56), dec!(10)), order(dec!(0.54), dec!(5))];
+
+        assert_eq!(best_bid(&bids), Some(dec!(0.50)));
+        assert_eq!(best_ask(&asks), Some(dec!(0.54)));
+        assert_eq!(mid_price(&bids, &asks), Some(dec!(0.52)));
+        assert_eq!(spread(&bids, &asks), Some(dec!(0.04)));
+    }
+
+    #[test]
+    fn test_top_of_book_empty_side() {
+        let asks = vec![order(dec!(0.54), dec!(5))];
+
+        assert_eq!(best_bid(&[]), None);
+        assert_eq!(best_ask(&asks), Some(dec!(0.54)));
+        assert_eq!(mid_price(&[], &asks), None);
+        assert_eq!(spread(&[], &asks), None);
+    }
+
+    #[test]
+    fn test_liquidity_within_bps() {
+        let bids = vec![order(dec!(0.49), dec!(100)), order(dec!(0.40), dec!(500))];
+        let asks = vec![order(dec!(0.51), dec!(50)), order(dec!(0.52), dec!(25))];
+
+        // Mid 0.50, 200 bps band = 0.49..=0.51
+        assert_eq!(
+            liquidity_within_bps(&bids, &asks, 200),
+            (dec!(100), dec!(50))
+        );
+        assert_eq!(
+            liquidity_within_bps(&bids, &asks, 2000),
+            (dec!(600), dec!(75))
+        );
+        assert_eq!(liquidity_within_bps(&bids, &asks, 0), (dec!(0), dec!(0)));
+    }
+
+    #[test]
+    fn test_liquidity_within_bps_one_sided_and_empty() {
+        let asks = vec![order(dec!(0.50), dec!(10)), order(dec!(0.60), dec!(10))];
+
+        // Mid falls back to the best ask
+        assert_eq!(liquidity_within_bps(&[], &asks, 100), (dec!(0), dec!(10)));
+        assert_eq!(liquidity_within_bps(&[], &[], 100), (dec!(0), dec!(0)));
+    }
+
+    #[test]
+    fn test_is_crossing() {
+        let (bid, ask) = (Some(dec!(0.48)), Some(dec!(0.52)));
+
+        assert!(!is_crossing(dec!(0.51), Side::Buy, bid, ask));
+        assert!(is_crossing(dec!(0.52), Side::Buy, bid, ask));
+        assert!(is_crossing(dec!(0.60), Side::Buy, bid, ask));
+        assert!(!is_crossing(dec!(0.49), Side::Sell, bid, ask));
+        assert!(is_crossing(dec!(0.48), Side::Sell, bid, ask));
+
+        // An empty side can't be crossed
+        assert!(!is_crossing(dec!(0.99), Side::Buy, bid, None));
+        assert!(!is_crossing(dec!(0.01), Side::Sell, None, ask));
+    }
+
+    #[test]
+    fn test_complement_price() {
+        assert_eq!(complement_price(dec!(0.35)).unwrap(), dec!(0.65));
+        assert!(matches!(
+            complement_price(dec!(0)),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(complement_price(dec!(1)).is_err());
+        assert!(complement_price(dec!(1.2)).is_err());
+    }
+
+    #[test]
+    fn test_implied_no_price() {
+        let mut book = OrderBookSummary {
+            market: "m".to_string(),
+            asset_id: "yes".to_string(),
+            hash: "h".to_string(),
+            timestamp: 0,
+            bids: vec![order(dec!(0.58), dec!(10))],
+            asks: vec![order(dec!(0.62), dec!(10))],
+        };
+        assert_eq!(implied_no_price(&book).unwrap(), dec!(0.40));
+
+        book.asks.clear();
+        assert!(matches!(
+            implied_no_price(&book),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_market_price_for_shares_partial_last_level() {
+        let asks = vec![order(dec!(0.50), dec!(100)), order(dec!(0.51), dec!(200))];
+
+        // 100 @ 0.50 + 50 @ 0.51 = 75.50 for 150 shares, truncated to 6 decimals
+        let price = calculate_market_price_for_shares(&asks, dec!(150), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.503333));
+
+        let bids = vec![order(dec!(0.48), dec!(10)), order(dec!(0.49), dec!(10))];
+        let price = calculate_market_price_for_shares(&bids, dec!(15), Side::Sell).unwrap();
+        // (4.90 + 2.40) / 15
+        assert_eq!(price, dec!(0.486666));
+
+        assert!(calculate_market_price_for_shares(&bids, dec!(21), Side::Sell).is_err());
+    }
 }
@@ -1,9 +1,12 @@
 use rust_decimal::Decimal;
-use rust_decimal::RoundingStrategy::{AwayFromZero, MidpointTowardZero, ToZero};
+use rust_decimal::RoundingStrategy::{self, AwayFromZero, MidpointTowardZero, ToZero};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
+use crate::error::{Error, Result};
+use crate::types::Side;
+
 /// Rounding configuration for a specific tick size
 #[derive(Debug, Clone, Copy)]
 pub struct RoundConfig {
@@ -50,6 +53,47 @@ pub static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::
     ])
 });
 
+/// Decimals of USDC and outcome token amounts on chain
+pub const TOKEN_DECIMALS: u32 = 6;
+
+/// Rounding of amounts derived by the pricing helpers
+///
+/// Average fill prices ([`calculate_market_price`](super::calculate_market_price),
+/// [`calculate_average_fill_price`](super::calculate_average_fill_price),
+/// [`calculate_market_fill`](super::calculate_market_fill)) and fees
+/// ([`estimate_order_cost`](super::estimate_order_cost)) are divisions or
+/// products that don't end on a representable amount; they are rounded with
+/// this configuration, which keeps the helpers consistent with each other.
+///
+/// The default truncates to [`TOKEN_DECIMALS`], like the exchange contract,
+/// which settles in 6-decimal base units and rounds every division down.
+/// Order amounts follow the per-tick rules of [`RoundConfig`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundingConfig {
+    pub mode: RoundingStrategy,
+    /// Decimal places to keep
+    pub scale: u32,
+}
+
+impl RoundingConfig {
+    pub const fn new(mode: RoundingStrategy, scale: u32) -> Self {
+        Self { mode, scale }
+    }
+
+    /// Round `value`, dropping trailing zeros
+    pub fn round(&self, value: Decimal) -> Decimal {
+        value
+            .round_dp_with_strategy(self.scale, self.mode)
+            .normalize()
+    }
+}
+
+impl Default for RoundingConfig {
+    fn default() -> Self {
+        Self::new(ToZero, TOKEN_DECIMALS)
+    }
+}
+
 /// Convert decimal amount to token units (multiply by 1e6 and round)
 pub fn decimal_to_token_u64(amt: Decimal) -> u64 {
     let mut amt = Decimal::from_scientific("1e6").expect("1e6 is not scientific") * amt;
@@ -70,9 +114,97 @@ pub fn fix_amount_rounding(mut amt: Decimal, round_config: &RoundConfig) -> Deci
     amt
 }
 
+/// Round a price to a multiple of `tick_size` for a maker order
+///
+/// Prices are rounded away from the counterparty so the order never trades at
+/// a worse price than requested: BUY prices round down (floor) and SELL prices
+/// round up (ceiling). A price already on a tick is returned unchanged. This
+/// keeps a resting (maker) quote at or behind the intended level; an order
+/// that must cross the spread (taker) could end up a tick short of the book,
+/// so round it with [`round_to_tick_taker`] instead.
+///
+/// The result is clamped to `[tick_size, 1 - tick_size]`, the valid price range
+/// of a binary market. A non-positive `tick_size`, or one above 0.5 that leaves
+/// no valid price, returns `price` unchanged.
+pub fn round_to_tick(price: Decimal, tick_size: Decimal, side: Side) -> Decimal {
+    round_price(price, tick_size, side == Side::Sell)
+}
+
+/// Round a price to a multiple of `tick_size` for a taker order
+///
+/// Prices are rounded towards the counterparty so the order still reaches the
+/// level it was priced at: BUY prices round up (ceiling) and SELL prices round
+/// down (floor). The fill can be up to one tick worse than `price`. A price
+/// already on a tick is returned unchanged.
+///
+/// Clamped like [`round_to_tick`].
+pub fn round_to_tick_taker(price: Decimal, tick_size: Decimal, side: Side) -> Decimal {
+    round_price(price, tick_size, side == Side::Buy)
+}
+
+fn round_price(price: Decimal, tick_size: Decimal, round_up: bool) -> Decimal {
+    let max_price = Decimal::ONE - tick_size;
+    if tick_size <= Decimal::ZERO || tick_size > max_price {
+        return price;
+    }
+
+    let ticks = price / tick_size;
+    let ticks = if round_up {
+        ticks.ceil()
+    } else {
+        ticks.floor()
+    };
+
+    let rounded = (ticks * tick_size).round_dp(tick_size.scale());
+    rounded.clamp(tick_size, max_price)
+}
+
+/// Floor an order size to `size_decimals` places and check the market minimum
+///
+/// Sizes are always rounded down so an order never exceeds the requested size.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidOrder`] if the rounded size is below `min_order_size`.
+pub fn round_size(size: Decimal, min_order_size: Decimal, size_decimals: u32) -> Result<Decimal> {
+    let rounded = size.round_dp_with_strategy(size_decimals, ToZero);
+
+    if rounded < min_order_size {
+        return Err(Error::InvalidOrder(format!(
+            "Size {} (rounded from {}) is below the minimum order size of {}",
+            rounded, size, min_order_size
+        )));
+    }
+
+    Ok(rounded)
+}
+
+/// Format a price with exactly the decimals of `tick_size`
+///
+/// Extra decimals are truncated like the prices of built orders, and missing
+/// ones are padded with zeros, so `0.5` on a `0.01` tick becomes `"0.50"`.
+pub fn price_to_string(price: Decimal, tick_size: Decimal) -> String {
+    fixed_scale(price, tick_size.normalize().scale())
+}
+
+/// Format a size with exactly `decimals` places, truncating extra decimals
+///
+/// Order sizes use 2 decimals (see [`RoundConfig::size`]), so `100` becomes
+/// `"100.00"`.
+pub fn size_to_string(size: Decimal, decimals: u32) -> String {
+    fixed_scale(size, decimals)
+}
+
+fn fixed_scale(value: Decimal, scale: u32) -> String {
+    let mut value = value.round_dp_with_strategy(scale, ToZero);
+    value.rescale(scale);
+    value.to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_rounding_configs_exist() {
@@ -87,4 +219,123 @@ mod tests {
         let result = decimal_to_token_u64(Decimal::from_str("1.5").unwrap());
         assert_eq!(result, 1_500_000);
     }
+
+    #[test]
+    fn test_round_to_tick_by_side() {
+        assert_eq!(
+            round_to_tick(dec!(0.5678), dec!(0.01), Side::Buy),
+            dec!(0.56)
+        );
+        assert_eq!(
+            round_to_tick(dec!(0.5678), dec!(0.01), Side::Sell),
+            dec!(0.57)
+        );
+        assert_eq!(
+            round_to_tick(dec!(0.5678), dec!(0.001), Side::Buy),
+            dec!(0.567)
+        );
+        assert_eq!(
+            round_to_tick(dec!(0.5678), dec!(0.001), Side::Sell),
+            dec!(0.568)
+        );
+    }
+
+    #[test]
+    fn test_round_to_tick_on_boundary() {
+        assert_eq!(round_to_tick(dec!(0.56), dec!(0.01), Side::Buy), dec!(0.56));
+        assert_eq!(
+            round_to_tick(dec!(0.56), dec!(0.01), Side::Sell),
+            dec!(0.56)
+        );
+        assert_eq!(
+            round_to_tick(dec!(0.5600), dec!(0.01), Side::Sell),
+            dec!(0.56)
+        );
+    }
+
+    #[test]
+    fn test_round_to_tick_taker_crosses() {
+        assert_eq!(
+            round_to_tick_taker(dec!(0.5678), dec!(0.01), Side::Buy),
+            dec!(0.57)
+        );
+        assert_eq!(
+            round_to_tick_taker(dec!(0.5678), dec!(0.01), Side::Sell),
+            dec!(0.56)
+        );
+        assert_eq!(
+            round_to_tick_taker(dec!(0.56), dec!(0.01), Side::Buy),
+            dec!(0.56)
+        );
+        assert_eq!(
+            round_to_tick_taker(dec!(0.999), dec!(0.01), Side::Buy),
+            dec!(0.99)
+        );
+    }
+
+    #[test]
+    fn test_round_to_tick_clamps_to_valid_range() {
+        assert_eq!(
+            round_to_tick(dec!(0.995), dec!(0.01), Side::Sell),
+            dec!(0.99)
+        );
+        assert_eq!(
+            round_to_tick(dec!(0.004), dec!(0.01), Side::Buy),
+            dec!(0.01)
+        );
+        assert_eq!(round_to_tick(dec!(0.5), dec!(0), Side::Buy), dec!(0.5));
+        // A tick above 0.5 has no valid price to clamp to
+        assert_eq!(round_to_tick(dec!(0.3), dec!(0.6), Side::Buy), dec!(0.3));
+        assert_eq!(
+            round_to_tick_taker(dec!(0.7), dec!(0.75), Side::Sell),
+            dec!(0.7)
+        );
+        assert_eq!(round_to_tick(dec!(0.7), dec!(0.5), Side::Buy), dec!(0.5));
+    }
+
+    #[test]
+    fn test_price_to_string() {
+        assert_eq!(price_to_string(dec!(0.5), dec!(0.01)), "0.50");
+        assert_eq!(price_to_string(dec!(0.5678), dec!(0.01)), "0.56");
+        assert_eq!(price_to_string(dec!(0.567), dec!(0.001)), "0.567");
+        assert_eq!(price_to_string(dec!(0.1), dec!(0.0001)), "0.1000");
+        assert_eq!(price_to_string(dec!(0.99), dec!(0.1)), "0.9");
+        // Trailing zeros of the tick size don't add decimals
+        assert_eq!(price_to_string(dec!(0.5), dec!(0.010)), "0.50");
+    }
+
+    #[test]
+    fn test_size_to_string() {
+        assert_eq!(size_to_string(dec!(100), 2), "100.00");
+        assert_eq!(size_to_string(dec!(12.3456), 2), "12.34");
+        assert_eq!(size_to_string(dec!(5.10), 0), "5");
+        assert_eq!(size_to_string(dec!(0.000001), 6), "0.000001");
+    }
+
+    #[test]
+    fn test_round_size_floors_to_decimals() {
+        assert_eq!(round_size(dec!(12.3456), dec!(5), 2).unwrap(), dec!(12.34));
+        assert_eq!(round_size(dec!(12.3456), dec!(5), 0).unwrap(), dec!(12));
+        assert_eq!(round_size(dec!(5), dec!(5), 2).unwrap(), dec!(5));
+    }
+
+    #[test]
+    fn test_round_size_below_minimum() {
+        let err = round_size(dec!(4.999), dec!(5), 2).unwrap_err();
+        match err {
+            Error::InvalidOrder(msg) => assert!(msg.contains("minimum order size of 5"), "{}", msg),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_rounding_config() {
+        let truncate = RoundingConfig::default();
+        assert_eq!(truncate.round(dec!(0.5066666666)), dec!(0.506666));
+        assert_eq!(truncate.round(dec!(1.50)), dec!(1.5));
+
+        let bankers = RoundingConfig::new(RoundingStrategy::MidpointNearestEven, 2);
+        assert_eq!(bankers.round(dec!(0.125)), dec!(0.12));
+        assert_eq!(bankers.round(dec!(0.135)), dec!(0.14));
+    }
 }
@@ -0,0 +1,130 @@
+use crate::error::{Error, Result};
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy::ToZero;
+use std::time::{Duration, Instant};
+
+/// Decimal places of an order size, the same for every tick size
+const SIZE_DECIMALS: u32 = 2;
+
+/// Split `total_size` into `num_slices` child orders spaced `interval` apart
+///
+/// Planning only: nothing is posted. The first slice is scheduled now and
+/// each following one `interval` later. Every slice gets `total_size /
+/// num_slices` floored to the 2 decimals an order size allows, and the last
+/// slice also takes the remainder, so the slices add up to `total_size`
+/// exactly. With less than 0.01 per slice, fewer slices are returned so that
+/// none is zero: one per 0.01 of `total_size`, and at least one.
+/// Post each slice with
+/// [`TradingClient::create_and_post_order`](crate::client::TradingClient::create_and_post_order)
+/// once its time has come.
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `num_slices` is zero,
+/// `total_size` isn't positive, or the schedule doesn't fit in an [`Instant`].
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::twap_slices;
+/// use rust_decimal_macros::dec;
+/// use std::time::Duration;
+///
+/// let slices = twap_slices(dec!(100), 3, Duration::from_secs(60)).unwrap();
+/// let sizes: Vec<_> = slices.iter().map(|(_, size)| *size).collect();
+/// assert_eq!(sizes, vec![dec!(33.33), dec!(33.33), dec!(33.34)]);
+/// assert_eq!(slices[1].0 - slices[0].0, Duration::from_secs(60));
+/// ```
+pub fn twap_slices(
+    total_size: Decimal,
+    num_slices: u32,
+    interval: Duration,
+) -> Result<Vec<(Instant, Decimal)>> {
+    if num_slices == 0 {
+        return Err(Error::InvalidParameter(
+            "num_slices must be greater than 0".to_string(),
+        ));
+    }
+    if total_size <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "total_size ({}) must be positive",
+            total_size
+        )));
+    }
+
+    let min_size = Decimal::new(1, SIZE_DECIMALS);
+    let max_slices = (total_size / min_size).floor();
+    let num_slices = if max_slices < Decimal::from(num_slices) {
+        u32::try_from(max_slices).unwrap_or(1).max(1)
+    } else {
+        num_slices
+    };
+
+    let slice_size =
+        (total_size / Decimal::from(num_slices)).round_dp_with_strategy(SIZE_DECIMALS, ToZero);
+    let last_size = total_size - slice_size * Decimal::from(num_slices - 1);
+
+    let start = Instant::now();
+    (0..num_slices)
+        .map(|i| {
+            let size = if i + 1 == num_slices {
+                last_size
+            } else {
+                slice_size
+            };
+            let at = interval
+                .checked_mul(i)
+                .and_then(|offset| start.checked_add(offset))
+                .ok_or_else(|| {
+                    Error::InvalidParameter(format!(
+                        "slice {} at {:?} intervals overflows the schedule",
+                        i, interval
+                    ))
+                })?;
+            Ok((at, size))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_slices_add_up_and_are_evenly_spaced() {
+        let interval = Duration::from_millis(250);
+        let slices = twap_slices(dec!(10), 4, interval).unwrap();
+        assert_eq!(slices.len(), 4);
+        assert!(slices.iter().all(|(_, size)| *size == dec!(2.5)));
+        for pair in slices.windows(2) {
+            assert_eq!(pair[1].0 - pair[0].0, interval);
+        }
+
+        // Less than a cent per slice: one slice per cent instead of zero-sized ones
+        let slices = twap_slices(dec!(0.05), 10, interval).unwrap();
+        assert_eq!(slices.len(), 5);
+        assert!(slices.iter().all(|(_, size)| *size == dec!(0.01)));
+        let slices = twap_slices(dec!(0.005), 3, interval).unwrap();
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].1, dec!(0.005));
+
+        let single = twap_slices(dec!(7.777), 1, interval).unwrap();
+        assert_eq!(single[0].1, dec!(7.777));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let interval = Duration::from_secs(1);
+        assert!(matches!(
+            twap_slices(dec!(10), 0, interval),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            twap_slices(Decimal::ZERO, 3, interval),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            twap_slices(dec!(10), 3, Duration::MAX),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+}
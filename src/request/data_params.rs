@@ -1,5 +1,40 @@
-/// Sort direction for activity queries
-#[derive(Debug, Clone)]
+use crate::error::{Error, Result};
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+/// Largest `offset` the Data API accepts for trades and activity
+const MAX_OFFSET: u32 = 10_000;
+/// Largest `limit` the Data API accepts for trades
+const MAX_TRADE_LIMIT: u32 = 10_000;
+/// Largest `limit` the Data API accepts for activity
+const MAX_ACTIVITY_LIMIT: u32 = 500;
+
+/// Reject a `limit`/`offset` pair outside the range an endpoint accepts
+fn check_page(limit: Option<u32>, offset: Option<u32>, max_limit: u32) -> Result<()> {
+    if let Some(limit) = limit.filter(|&limit| limit > max_limit) {
+        return Err(Error::InvalidParameter(format!(
+            "limit ({}) must not exceed {}",
+            limit, max_limit
+        )));
+    }
+    if let Some(offset) = offset.filter(|&offset| offset > MAX_OFFSET) {
+        return Err(Error::InvalidParameter(format!(
+            "offset ({}) must not exceed {}",
+            offset, MAX_OFFSET
+        )));
+    }
+    Ok(())
+}
+
+/// Sort direction for Data API position and activity queries
+///
+/// Every Data API endpoint taking a `sortDirection` expects it uppercase
+/// (`ASC`/`DESC`), which is what both serde and [`as_str`](Self::as_str)
+/// emit. The Gamma API has no direction parameter and takes a boolean
+/// `ascending` instead; use [`is_ascending`](Self::is_ascending) with
+/// [`GammaMarketParams::with_order`](super::GammaMarketParams::with_order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum SortDirection {
     Asc,
     Desc,
@@ -12,28 +47,155 @@ impl SortDirection {
             SortDirection::Desc => "DESC",
         }
     }
+
+    /// Value of the Gamma API `ascending` flag for this direction
+    pub fn is_ascending(&self) -> bool {
+        matches!(self, SortDirection::Asc)
+    }
 }
 
 /// Sort field for activity queries
-#[derive(Debug, Clone)]
+///
+/// The API silently falls back to timestamp order for unknown values.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum ActivitySortBy {
     Timestamp,
+    /// Number of shares traded
+    Tokens,
+    /// USDC value of the activity
+    Cash,
 }
 
 impl ActivitySortBy {
     pub fn as_str(&self) -> &str {
         match self {
             ActivitySortBy::Timestamp => "TIMESTAMP",
+            ActivitySortBy::Tokens => "TOKENS",
+            ActivitySortBy::Cash => "CASH",
         }
     }
 }
 
+/// Sort field for position queries
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PositionSortBy {
+    Current,
+    Initial,
+    Tokens,
+    CashPnl,
+    PercentPnl,
+    Title,
+    Resolving,
+    Price,
+}
+
+impl PositionSortBy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PositionSortBy::Current => "CURRENT",
+            PositionSortBy::Initial => "INITIAL",
+            PositionSortBy::Tokens => "TOKENS",
+            PositionSortBy::CashPnl => "CASHPNL",
+            PositionSortBy::PercentPnl => "PERCENTPNL",
+            PositionSortBy::Title => "TITLE",
+            PositionSortBy::Resolving => "RESOLVING",
+            PositionSortBy::Price => "PRICE",
+        }
+    }
+}
+
+/// Query parameters for the positions endpoint with offset/limit pagination and sorting
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionQueryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    /// Comma-separated condition IDs to restrict the positions to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market: Option<String>,
+    /// Only include positions of at least this many shares
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_threshold: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redeemable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mergeable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<PositionSortBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl PositionQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn with_market(mut self, condition_id: impl Into<String>) -> Self {
+        self.market = Some(condition_id.into());
+        self
+    }
+
+    pub fn with_size_threshold(mut self, size_threshold: Decimal) -> Self {
+        self.size_threshold = Some(size_threshold);
+        self
+    }
+
+    pub fn with_redeemable(mut self, redeemable: bool) -> Self {
+        self.redeemable = Some(redeemable);
+        self
+    }
+
+    pub fn with_mergeable(mut self, mergeable: bool) -> Self {
+        self.mergeable = Some(mergeable);
+        self
+    }
+
+    pub fn with_sort_by(mut self, sort_by: PositionSortBy) -> Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn with_sort_direction(mut self, sort_direction: SortDirection) -> Self {
+        self.sort_direction = Some(sort_direction);
+        self
+    }
+}
+
 /// Query parameters for trade endpoints with offset/limit pagination
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TradeQueryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub taker_only: Option<bool>,
+    /// Condition ID of the market
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market: Option<String>,
+    /// Token ID of the traded outcome
+    #[serde(rename = "asset_id", skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<String>,
+    #[serde(rename = "maker_address", skip_serializing_if = "Option::is_none")]
+    pub maker_address: Option<String>,
+    #[serde(rename = "taker_address", skip_serializing_if = "Option::is_none")]
+    pub taker_address: Option<String>,
 }
 
 impl TradeQueryParams {
@@ -56,6 +218,42 @@ impl TradeQueryParams {
         self
     }
 
+    pub fn with_market(mut self, condition_id: impl Into<String>) -> Self {
+        self.market = Some(condition_id.into());
+        self
+    }
+
+    pub fn with_asset_id(mut self, asset_id: impl Into<String>) -> Self {
+        self.asset_id = Some(asset_id.into());
+        self
+    }
+
+    pub fn with_maker_address(mut self, maker_address: impl Into<String>) -> Self {
+        self.maker_address = Some(maker_address.into());
+        self
+    }
+
+    pub fn with_taker_address(mut self, taker_address: impl Into<String>) -> Self {
+        self.taker_address = Some(taker_address.into());
+        self
+    }
+
+    /// Catch combinations the API rejects or answers with an empty list
+    ///
+    /// Checks that:
+    /// - `limit` and `offset` are at most 10,000
+    /// - `maker_address` is not combined with `taker_only(true)`, which
+    ///   excludes every trade where the address was the maker
+    pub fn validate(&self) -> Result<()> {
+        check_page(self.limit, self.offset, MAX_TRADE_LIMIT)?;
+        if self.taker_only == Some(true) && self.maker_address.is_some() {
+            return Err(Error::InvalidParameter(
+                "maker_address filter requires taker_only(false)".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
 
@@ -68,6 +266,18 @@ impl TradeQueryParams {
         if let Some(taker_only) = self.taker_only {
             params.push(format!("takerOnly={}", taker_only));
         }
+        if let Some(ref market) = self.market {
+            params.push(format!("market={}", market));
+        }
+        if let Some(ref asset_id) = self.asset_id {
+            params.push(format!("asset_id={}", asset_id));
+        }
+        if let Some(ref maker_address) = self.maker_address {
+            params.push(format!("maker_address={}", maker_address));
+        }
+        if let Some(ref taker_address) = self.taker_address {
+            params.push(format!("taker_address={}", taker_address));
+        }
 
         if params.is_empty() {
             String::new()
@@ -78,12 +288,23 @@ impl TradeQueryParams {
 }
 
 /// Query parameters for activity endpoints with offset/limit pagination and sorting
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ActivityQueryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_by: Option<ActivitySortBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_direction: Option<SortDirection>,
+    /// Only include activity at or after this Unix timestamp (seconds)
+    #[serde(rename = "start", skip_serializing_if = "Option::is_none")]
+    pub start_ts: Option<i64>,
+    /// Only include activity at or before this Unix timestamp (seconds)
+    #[serde(rename = "end", skip_serializing_if = "Option::is_none")]
+    pub end_ts: Option<i64>,
 }
 
 impl ActivityQueryParams {
@@ -91,6 +312,23 @@ impl ActivityQueryParams {
         Self::default()
     }
 
+    /// Start building query parameters; unset fields are left as `None`
+    ///
+    /// # Example
+    /// ```
+    /// use polymarket_rs::request::{ActivityQueryParams, ActivitySortBy, SortDirection};
+    ///
+    /// let params = ActivityQueryParams::builder()
+    ///     .sort_by(ActivitySortBy::Timestamp)
+    ///     .direction(SortDirection::Desc)
+    ///     .limit(100)
+    ///     .build();
+    /// assert_eq!(params.limit, Some(100));
+    /// ```
+    pub fn builder() -> ActivityQueryParamsBuilder {
+        ActivityQueryParamsBuilder::default()
+    }
+
     pub fn with_limit(mut self, limit: u32) -> Self {
         self.limit = Some(limit);
         self
@@ -111,6 +349,34 @@ impl ActivityQueryParams {
         self
     }
 
+    pub fn with_start_ts(mut self, start_ts: i64) -> Self {
+        self.start_ts = Some(start_ts);
+        self
+    }
+
+    pub fn with_end_ts(mut self, end_ts: i64) -> Self {
+        self.end_ts = Some(end_ts);
+        self
+    }
+
+    /// Catch combinations the API rejects or answers with an empty list
+    ///
+    /// Checks that:
+    /// - the time window is not inverted (`start_ts` after `end_ts`)
+    /// - `limit` is at most 500 and `offset` at most 10,000
+    pub fn validate(&self) -> Result<()> {
+        check_page(self.limit, self.offset, MAX_ACTIVITY_LIMIT)?;
+        if let (Some(start), Some(end)) = (self.start_ts, self.end_ts) {
+            if start > end {
+                return Err(Error::InvalidParameter(format!(
+                    "start_ts ({}) must not be after end_ts ({})",
+                    start, end
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
 
@@ -126,6 +392,12 @@ impl ActivityQueryParams {
         if let Some(offset) = self.offset {
             params.push(format!("offset={}", offset));
         }
+        if let Some(start_ts) = self.start_ts {
+            params.push(format!("start={}", start_ts));
+        }
+        if let Some(end_ts) = self.end_ts {
+            params.push(format!("end={}", end_ts));
+        }
 
         if params.is_empty() {
             String::new()
@@ -134,3 +406,221 @@ impl ActivityQueryParams {
         }
     }
 }
+
+/// Builder for [`ActivityQueryParams`]
+#[derive(Debug, Clone, Default)]
+pub struct ActivityQueryParamsBuilder {
+    params: ActivityQueryParams,
+}
+
+impl ActivityQueryParamsBuilder {
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by(mut self, sort_by: ActivitySortBy) -> Self {
+        self.params.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn direction(mut self, direction: SortDirection) -> Self {
+        self.params.sort_direction = Some(direction);
+        self
+    }
+
+    pub fn start_ts(mut self, start_ts: i64) -> Self {
+        self.params.start_ts = Some(start_ts);
+        self
+    }
+
+    pub fn end_ts(mut self, end_ts: i64) -> Self {
+        self.params.end_ts = Some(end_ts);
+        self
+    }
+
+    pub fn build(self) -> ActivityQueryParams {
+        self.params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<Q: Serialize>(query: &Q) -> String {
+        reqwest::Client::new()
+            .get("http://localhost/")
+            .query(query)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn test_trade_query_serialization() {
+        assert_eq!(encode(&TradeQueryParams::new()), "");
+
+        let params = TradeQueryParams::new().with_limit(10).with_taker_only(true);
+        assert_eq!(encode(&params), "limit=10&takerOnly=true");
+    }
+
+    #[test]
+    fn test_trade_query_filters() {
+        let params = TradeQueryParams::new()
+            .with_market("0xabc")
+            .with_asset_id("123")
+            .with_taker_address("0xdef");
+        assert_eq!(
+            encode(&params),
+            "market=0xabc&asset_id=123&taker_address=0xdef"
+        );
+        assert_eq!(
+            params.to_query_string(),
+            "&market=0xabc&asset_id=123&taker_address=0xdef"
+        );
+    }
+
+    #[test]
+    fn test_position_query_serialization() {
+        assert_eq!(encode(&PositionQueryParams::new()), "");
+
+        let params = PositionQueryParams::new()
+            .with_limit(50)
+            .with_size_threshold(Decimal::ONE)
+            .with_redeemable(true)
+            .with_sort_by(PositionSortBy::CashPnl)
+            .with_sort_direction(SortDirection::Desc);
+        assert_eq!(
+            encode(&params),
+            "limit=50&sizeThreshold=1&redeemable=true&sortBy=CASHPNL&sortDirection=DESC"
+        );
+    }
+
+    #[test]
+    fn test_activity_query_serialization() {
+        let params = ActivityQueryParams::new()
+            .with_limit(5)
+            .with_sort_by(ActivitySortBy::Timestamp)
+            .with_sort_direction(SortDirection::Desc);
+        assert_eq!(
+            encode(&params),
+            "limit=5&sortBy=TIMESTAMP&sortDirection=DESC"
+        );
+    }
+
+    #[test]
+    fn test_activity_sort_by_values() {
+        for (sort_by, expected) in [
+            (ActivitySortBy::Timestamp, "TIMESTAMP"),
+            (ActivitySortBy::Tokens, "TOKENS"),
+            (ActivitySortBy::Cash, "CASH"),
+        ] {
+            assert_eq!(sort_by.as_str(), expected);
+            let params = ActivityQueryParams::new().with_sort_by(sort_by);
+            assert_eq!(encode(&params), format!("sortBy={}", expected));
+        }
+    }
+
+    #[test]
+    fn test_activity_time_window() {
+        let params = ActivityQueryParams::new()
+            .with_start_ts(1700000000)
+            .with_end_ts(1700086400);
+        assert!(params.validate().is_ok());
+        assert_eq!(encode(&params), "start=1700000000&end=1700086400");
+
+        let inverted = ActivityQueryParams::new()
+            .with_start_ts(1700086400)
+            .with_end_ts(1700000000);
+        assert!(matches!(
+            inverted.validate(),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_trade_query_validation() {
+        assert!(TradeQueryParams::new()
+            .with_limit(10_000)
+            .validate()
+            .is_ok());
+        assert!(TradeQueryParams::new()
+            .with_limit(10_001)
+            .validate()
+            .is_err());
+        assert!(TradeQueryParams::new()
+            .with_offset(10_001)
+            .validate()
+            .is_err());
+
+        let maker = TradeQueryParams::new().with_maker_address("0xabc");
+        assert!(maker.clone().validate().is_ok());
+        assert!(maker.clone().with_taker_only(false).validate().is_ok());
+        assert!(matches!(
+            maker.with_taker_only(true).validate(),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_activity_page_bounds() {
+        assert!(ActivityQueryParams::new()
+            .with_limit(500)
+            .validate()
+            .is_ok());
+        assert!(ActivityQueryParams::new()
+            .with_limit(501)
+            .validate()
+            .is_err());
+        assert!(ActivityQueryParams::new()
+            .with_offset(10_001)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_activity_builder_matches_with_methods() {
+        let built = ActivityQueryParams::builder()
+            .limit(100)
+            .offset(200)
+            .sort_by(ActivitySortBy::Timestamp)
+            .direction(SortDirection::Asc)
+            .build();
+        let chained = ActivityQueryParams::new()
+            .with_limit(100)
+            .with_offset(200)
+            .with_sort_by(ActivitySortBy::Timestamp)
+            .with_sort_direction(SortDirection::Asc);
+
+        assert_eq!(encode(&built), encode(&chained));
+        assert!(built.start_ts.is_none());
+    }
+
+    #[test]
+    fn test_sort_direction_casing_per_endpoint() {
+        for (direction, expected) in [(SortDirection::Asc, "ASC"), (SortDirection::Desc, "DESC")] {
+            let query = format!("sortDirection={}", expected);
+            assert_eq!(direction.as_str(), expected);
+            // /positions is serialized by serde, /activity by `to_query_string`
+            let positions = PositionQueryParams::new().with_sort_direction(direction);
+            assert_eq!(encode(&positions), query);
+            let activity = ActivityQueryParams::new().with_sort_direction(direction);
+            assert_eq!(activity.to_query_string(), format!("&{}", query));
+            assert_eq!(encode(&activity), query);
+        }
+
+        let gamma = crate::request::GammaMarketParams::new()
+            .with_order("volume", SortDirection::Asc.is_ascending());
+        assert!(gamma.to_query_string().contains("ascending=true"));
+        assert!(!SortDirection::Desc.is_ascending());
+    }
+}
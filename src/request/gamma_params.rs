@@ -1,14 +1,26 @@
+use serde::Serialize;
+
 /// Query parameters for Gamma API market endpoints
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GammaMarketParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tag_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ascending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clob_token_ids: Option<String>,
 }
 
 impl GammaMarketParams {
@@ -60,6 +72,12 @@ impl GammaMarketParams {
         self
     }
 
+    /// Filter by CLOB token (asset) ID
+    pub fn with_clob_token_id(mut self, token_id: impl Into<String>) -> Self {
+        self.clob_token_ids = Some(token_id.into());
+        self
+    }
+
     /// Convert parameters to query string
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
@@ -88,6 +106,9 @@ impl GammaMarketParams {
         if let Some(ascending) = self.ascending {
             params.push(format!("ascending={}", ascending));
         }
+        if let Some(ref clob_token_ids) = self.clob_token_ids {
+            params.push(format!("clob_token_ids={}", clob_token_ids));
+        }
 
         if params.is_empty() {
             String::new()
@@ -97,6 +118,85 @@ impl GammaMarketParams {
     }
 }
 
+/// Query parameters for the Gamma API events endpoint
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GammaEventParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascending: Option<bool>,
+}
+
+impl GammaEventParams {
+    /// Create a new instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of results to return
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the pagination offset
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Filter for active events
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Filter for closed events
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Filter for archived events
+    pub fn with_archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Filter by tag ID
+    pub fn with_tag_id(mut self, tag_id: impl Into<String>) -> Self {
+        self.tag_id = Some(tag_id.into());
+        self
+    }
+
+    /// Filter by event slug
+    pub fn with_slug(mut self, slug: impl Into<String>) -> Self {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    /// Set the ordering field
+    pub fn with_order(mut self, order: impl Into<String>, ascending: bool) -> Self {
+        self.order = Some(order.into());
+        self.ascending = Some(ascending);
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
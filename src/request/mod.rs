@@ -2,6 +2,14 @@ mod data_params;
 mod gamma_params;
 mod pagination;
 
-pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
-pub use gamma_params::GammaMarketParams;
-pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+pub use data_params::{
+    ActivityQueryParams, ActivityQueryParamsBuilder, ActivitySortBy, PositionQueryParams,
+    PositionSortBy, SortDirection, TradeQueryParams,
+};
+pub use gamma_params::{GammaEventParams, GammaMarketParams};
+pub use pagination::{
+    collect_all, collect_all_until, collect_all_with, collect_all_with_retry, paginate_all,
+    paginate_all_with_retry, paginate_with, Collected, CursorPage, CursorPagination,
+    OffsetPagination, PaginationParams, PaginationStrategy, END_CURSOR, INITIAL_CURSOR,
+    MAX_PAGE_LIMIT,
+};
@@ -1,27 +1,81 @@
+use crate::error::{Error, Result};
+use crate::http::{retry_with, RetryPolicy};
+use crate::types::{
+    ClobTradesResponse, EarningsResponse, MarketsResponse, OpenOrdersResponse, RewardsResponse,
+    SimplifiedMarketsResponse,
+};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::Serialize;
+use std::future::Future;
+
 /// Pagination cursors for API requests
 pub const END_CURSOR: &str = "LTE=";
 pub const INITIAL_CURSOR: &str = "MA==";
 
+/// Largest page size accepted by the list endpoints
+///
+/// Endpoints with a different cap can override it with
+/// [`PaginationParams::with_max_limit`].
+pub const MAX_PAGE_LIMIT: u32 = 500;
+
 /// Pagination parameters for list endpoints
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PaginationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip)]
+    max_limit: u32,
 }
 
 impl PaginationParams {
     pub fn new() -> Self {
-        Self { next_cursor: None }
+        Self {
+            next_cursor: None,
+            limit: None,
+            max_limit: MAX_PAGE_LIMIT,
+        }
     }
 
     pub fn with_cursor(cursor: impl Into<String>) -> Self {
         Self {
             next_cursor: Some(cursor.into()),
+            ..Self::new()
         }
     }
 
     pub fn initial() -> Self {
-        Self {
-            next_cursor: Some(INITIAL_CURSOR.to_string()),
+        Self::with_cursor(INITIAL_CURSOR)
+    }
+
+    /// Set the page size
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if `limit` is 0 or above the
+    /// maximum (see [`MAX_PAGE_LIMIT`]).
+    pub fn with_limit(mut self, limit: u32) -> Result<Self> {
+        self.limit = Some(limit);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Override the maximum page size for endpoints with a different cap
+    pub fn with_max_limit(mut self, max_limit: u32) -> Self {
+        self.max_limit = max_limit;
+        self
+    }
+
+    /// Check that the limit, if set, is between 1 and the maximum
+    pub fn validate(&self) -> Result<()> {
+        match self.limit {
+            Some(limit) if limit == 0 || limit > self.max_limit => {
+                Err(Error::InvalidParameter(format!(
+                    "Pagination limit {} is out of range (1..={})",
+                    limit, self.max_limit
+                )))
+            }
+            _ => Ok(()),
         }
     }
 
@@ -30,6 +84,9 @@ impl PaginationParams {
         if let Some(ref cursor) = self.next_cursor {
             params.push(("next_cursor", cursor.clone()));
         }
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
         params
     }
 }
@@ -40,10 +97,713 @@ impl Default for PaginationParams {
     }
 }
 
+/// A single page of a cursor-paginated response
+pub trait CursorPage {
+    type Item;
+
+    /// Split the page into its items and the cursor of the next page
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+impl CursorPage for MarketsResponse {
+    type Item = crate::types::Market;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for SimplifiedMarketsResponse {
+    type Item = crate::types::SimplifiedMarket;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for OpenOrdersResponse {
+    type Item = crate::types::OpenOrder;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for ClobTradesResponse {
+    type Item = crate::types::ClobTrade;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for RewardsResponse {
+    type Item = crate::types::MarketReward;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl CursorPage for EarningsResponse {
+    type Item = crate::types::UserEarning;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl<T> CursorPage for (Vec<T>, Option<String>) {
+    type Item = T;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        self
+    }
+}
+
+/// Plain lists, as returned by the offset-paginated endpoints, have no cursor
+impl<T> CursorPage for Vec<T> {
+    type Item = T;
+
+    fn into_page(self) -> (Vec<Self::Item>, Option<String>) {
+        (self, None)
+    }
+}
+
+/// Returns true if `cursor` marks the last page
+fn is_end_cursor(cursor: &str) -> bool {
+    cursor.is_empty() || cursor == END_CURSOR
+}
+
+/// How the pages of a list endpoint are requested and walked
+///
+/// Two schemes are in use:
+/// - [`CursorPagination`]: the CLOB list endpoints (markets, open orders,
+///   trades, rewards) return an opaque `next_cursor`, starting at
+///   [`INITIAL_CURSOR`] and ending at [`END_CURSOR`].
+/// - [`OffsetPagination`]: the Gamma API (markets, events) and the Data API
+///   (positions, trades, activity) take `limit` and `offset` and return a
+///   plain list.
+///
+/// [`paginate_with`] and [`collect_all_with`] walk either kind.
+pub trait PaginationStrategy {
+    /// Parameters identifying one page
+    type Params: Clone;
+
+    /// Parameters of the first page
+    fn first_page(&self) -> Self::Params;
+
+    /// Parameters of the page after `current`, or `None` after the last page
+    ///
+    /// `items` is the number of items `current` returned and `next_cursor`
+    /// the cursor it reported, if any.
+    fn next_page(
+        &self,
+        current: &Self::Params,
+        items: usize,
+        next_cursor: Option<String>,
+    ) -> Option<Self::Params>;
+}
+
+/// Opaque cursor pagination of the CLOB endpoints, see [`PaginationStrategy`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CursorPagination;
+
+impl PaginationStrategy for CursorPagination {
+    type Params = PaginationParams;
+
+    fn first_page(&self) -> PaginationParams {
+        PaginationParams::initial()
+    }
+
+    fn next_page(
+        &self,
+        _current: &PaginationParams,
+        _items: usize,
+        next_cursor: Option<String>,
+    ) -> Option<PaginationParams> {
+        next_cursor
+            .filter(|cursor| !is_end_cursor(cursor))
+            .map(PaginationParams::with_cursor)
+    }
+}
+
+/// Offset/limit pagination of the Gamma and Data APIs, see [`PaginationStrategy`]
+///
+/// As a strategy it starts at its own offset and advances by `limit` until a
+/// page returns fewer than `limit` items.
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::client::GammaClient;
+/// use polymarket_rs::request::{collect_all_with, GammaMarketParams, OffsetPagination};
+/// use polymarket_rs::RetryPolicy;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = GammaClient::new("https://gamma-api.polymarket.com");
+/// let markets = collect_all_with(
+///     OffsetPagination::new(100),
+///     |page| {
+///         let params = GammaMarketParams::new()
+///             .with_limit(page.limit)
+///             .with_offset(page.offset);
+///         client.get_markets(Some(params))
+///     },
+///     RetryPolicy::none(),
+///     Some(1000),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetPagination {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl OffsetPagination {
+    /// Pages of `limit` items, starting at offset 0
+    pub fn new(limit: u32) -> Self {
+        Self { offset: 0, limit }
+    }
+
+    /// Start at `offset` instead of the first item
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl PaginationStrategy for OffsetPagination {
+    type Params = OffsetPagination;
+
+    fn first_page(&self) -> OffsetPagination {
+        *self
+    }
+
+    fn next_page(
+        &self,
+        current: &OffsetPagination,
+        items: usize,
+        _next_cursor: Option<String>,
+    ) -> Option<OffsetPagination> {
+        if self.limit == 0 || items < self.limit as usize {
+            return None;
+        }
+        Some(current.with_offset(current.offset.checked_add(self.limit)?))
+    }
+}
+
+/// Stream every item of a cursor-paginated endpoint
+///
+/// Calls `fetch` starting from [`INITIAL_CURSOR`] and follows `next_cursor`
+/// until the API returns [`END_CURSOR`], yielding each item in order.
+/// An error from any page is yielded and ends the stream.
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::paginate_all;
+/// use futures_util::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let mut markets = std::pin::pin!(paginate_all(|p| client.get_markets(Some(p))));
+///
+/// while let Some(market) = markets.next().await {
+///     println!("{}", market?.question);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate_all<P, F, Fut>(fetch: F) -> impl Stream<Item = Result<P::Item>>
+where
+    P: CursorPage,
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    paginate_with(CursorPagination, fetch, RetryPolicy::none())
+}
+
+/// Stream every item of a cursor-paginated endpoint, retrying failed pages
+///
+/// Like [`paginate_all`], but a page that fails with a retryable error (see
+/// [`Error::is_retryable`]) is fetched again with the same cursor after the
+/// backoff of `retry`, so a transient 5xx doesn't end a long walk. The error
+/// is yielded only once the attempts for that page are exhausted.
+///
+/// Requests made through an [`HttpClient`](crate::HttpClient) are already
+/// retried by the client; pass its
+/// [`retry_policy`](crate::HttpClient::retry_policy) to apply the same policy
+/// once more per page.
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::paginate_all_with_retry;
+/// use polymarket_rs::RetryPolicy;
+/// use futures_util::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let markets = paginate_all_with_retry(|p| client.get_markets(Some(p)), RetryPolicy::default());
+/// let mut markets = std::pin::pin!(markets);
+///
+/// while let Some(market) = markets.next().await {
+///     println!("{}", market?.question);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate_all_with_retry<P, F, Fut>(
+    fetch: F,
+    retry: RetryPolicy,
+) -> impl Stream<Item = Result<P::Item>>
+where
+    P: CursorPage,
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    paginate_with(CursorPagination, fetch, retry)
+}
+
+/// Stream every item of a paginated endpoint walked with `strategy`
+///
+/// Generalizes [`paginate_all_with_retry`] to any [`PaginationStrategy`]:
+/// `fetch` is called with the parameters of each page in turn, and a page
+/// that fails with a retryable error is fetched again with the same
+/// parameters after the backoff of `retry`.
+///
+/// The stream spawns no tasks: dropping it cancels the page request in
+/// flight and discards the items not yet yielded. To stop a walk on a
+/// signal and keep what was collected, use [`collect_all_until`].
+pub fn paginate_with<S, P, F, Fut>(
+    strategy: S,
+    fetch: F,
+    retry: RetryPolicy,
+) -> impl Stream<Item = Result<P::Item>>
+where
+    S: PaginationStrategy,
+    P: CursorPage,
+    F: FnMut(S::Params) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let initial = Some(strategy.first_page());
+
+    stream::try_unfold(
+        (strategy, fetch, retry, initial),
+        |(strategy, mut fetch, retry, page)| async move {
+            let Some(page) = page else {
+                return Ok::<_, crate::error::Error>(None);
+            };
+
+            // The page only advances once it has been fetched
+            let (items, next_cursor) = retry_with(&retry, || fetch(page.clone()))
+                .await?
+                .into_page();
+            let next = strategy.next_page(&page, items.len(), next_cursor);
+
+            Ok(Some((items, (strategy, fetch, retry, next))))
+        },
+    )
+    .map_ok(|items| stream::iter(items).map(Ok))
+    .try_flatten()
+}
+
+/// Collect every item of a cursor-paginated endpoint into a `Vec`
+///
+/// Follows the cursor like [`paginate_all`] and returns the first error from
+/// any page. With `max_items` set, stops fetching once that many items have
+/// been collected and truncates the result to the cap.
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::collect_all;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let markets = collect_all(|p| client.get_markets(Some(p)), Some(1000)).await?;
+/// println!("{} markets", markets.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_all<P, F, Fut>(fetch: F, max_items: Option<usize>) -> Result<Vec<P::Item>>
+where
+    P: CursorPage,
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let items = paginate_all(fetch);
+    match max_items {
+        Some(max) => items.take(max).try_collect().await,
+        None => items.try_collect().await,
+    }
+}
+
+/// Collect every item of a cursor-paginated endpoint, retrying failed pages
+///
+/// Follows the cursor like [`paginate_all_with_retry`] and returns the first
+/// error that outlasts the retries, with `max_items` capping the result like
+/// [`collect_all`].
+pub async fn collect_all_with_retry<P, F, Fut>(
+    fetch: F,
+    retry: RetryPolicy,
+    max_items: Option<usize>,
+) -> Result<Vec<P::Item>>
+where
+    P: CursorPage,
+    F: FnMut(PaginationParams) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    collect_all_with(CursorPagination, fetch, retry, max_items).await
+}
+
+/// Collect every item of a paginated endpoint walked with `strategy`
+///
+/// Follows the pages like [`paginate_with`], with `max_items` capping the
+/// result like [`collect_all`].
+pub async fn collect_all_with<S, P, F, Fut>(
+    strategy: S,
+    fetch: F,
+    retry: RetryPolicy,
+    max_items: Option<usize>,
+) -> Result<Vec<P::Item>>
+where
+    S: PaginationStrategy,
+    P: CursorPage,
+    F: FnMut(S::Params) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let items = paginate_with(strategy, fetch, retry);
+    match max_items {
+        Some(max) => items.take(max).try_collect().await,
+        None => items.try_collect().await,
+    }
+}
+
+/// Items collected by [`collect_all_until`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collected<T> {
+    /// Items of the pages fetched before the walk ended, in order
+    pub items: Vec<T>,
+    /// Whether the walk was cut short by the cancellation signal
+    pub cancelled: bool,
+}
+
+/// Collect a paginated endpoint like [`collect_all_with`], stopping early on `cancel`
+///
+/// Once `cancel` completes, the page request in flight is dropped and the
+/// items collected so far are returned with `cancelled` set; a walk that
+/// reaches the last page or `max_items` first isn't affected. Any future
+/// works as the signal, such as a `tokio::sync::watch` change, a timeout or
+/// `tokio::signal::ctrl_c()`, which makes a graceful shutdown keep the data
+/// already fetched. An error from a page is still returned as an error.
+///
+/// # Example
+/// ```no_run
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::{collect_all_until, CursorPagination};
+/// use polymarket_rs::RetryPolicy;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let markets = collect_all_until(
+///     CursorPagination,
+///     |p| client.get_markets(Some(p)),
+///     RetryPolicy::none(),
+///     None,
+///     async {
+///         let _ = tokio::signal::ctrl_c().await;
+///     },
+/// )
+/// .await?;
+/// println!("{} markets, cancelled: {}", markets.items.len(), markets.cancelled);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_all_until<S, P, F, Fut, C>(
+    strategy: S,
+    fetch: F,
+    retry: RetryPolicy,
+    max_items: Option<usize>,
+    cancel: C,
+) -> Result<Collected<P::Item>>
+where
+    S: PaginationStrategy,
+    P: CursorPage,
+    F: FnMut(S::Params) -> Fut,
+    Fut: Future<Output = Result<P>>,
+    C: Future<Output = ()>,
+{
+    let mut stream = std::pin::pin!(paginate_with(strategy, fetch, retry));
+    let mut cancel = std::pin::pin!(cancel);
+    let mut items = Vec::new();
+
+    while max_items.is_none_or(|max| items.len() < max) {
+        tokio::select! {
+            biased;
+            _ = &mut cancel => {
+                return Ok(Collected {
+                    items,
+                    cancelled: true,
+                })
+            }
+            item = stream.next() => match item {
+                Some(item) => items.push(item?),
+                None => break,
+            },
+        }
+    }
+
+    Ok(Collected {
+        items,
+        cancelled: false,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Fake endpoint returning two items per page across three pages
+    async fn fetch_page(params: PaginationParams) -> Result<(Vec<u32>, Option<String>)> {
+        match params.next_cursor.as_deref() {
+            Some(INITIAL_CURSOR) => Ok((vec![1, 2], Some("page2".to_string()))),
+            Some("page2") => Ok((vec![3, 4], Some("page3".to_string()))),
+            Some("page3") => Ok((vec![5], Some(END_CURSOR.to_string()))),
+            other => Err(Error::InvalidParameter(format!("bad cursor {:?}", other))),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate_all_walks_to_end_cursor() {
+        let items: Vec<u32> = paginate_all(fetch_page).try_collect().await.unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_all_propagates_errors() {
+        let stream = paginate_all(|params: PaginationParams| async move {
+            match params.next_cursor.as_deref() {
+                Some(INITIAL_CURSOR) => Ok((vec![1], Some("broken".to_string()))),
+                _ => Err(Error::Api {
+                    status: 500,
+                    message: "boom".to_string(),
+                }),
+            }
+        });
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(*results[0].as_ref().unwrap(), 1);
+        assert!(results[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_collect_all() {
+        let items = collect_all(fetch_page, None).await.unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_stops_at_max_items() {
+        let pages = std::cell::Cell::new(0);
+        let items = collect_all(
+            |params| {
+                pages.set(pages.get() + 1);
+                fetch_page(params)
+            },
+            Some(3),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(pages.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_returns_page_error() {
+        let result = collect_all(
+            |params: PaginationParams| async move {
+                match params.next_cursor.as_deref() {
+                    Some(INITIAL_CURSOR) => Ok((vec![1], Some("broken".to_string()))),
+                    _ => Err(Error::Api {
+                        status: 500,
+                        message: "boom".to_string(),
+                    }),
+                }
+            },
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(Error::Api { status: 500, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_retry_refetches_failed_page_with_same_cursor() {
+        let cursors = std::cell::RefCell::new(Vec::new());
+        let items = collect_all_with_retry(
+            |params: PaginationParams| {
+                let mut cursors = cursors.borrow_mut();
+                cursors.push(params.next_cursor.clone().unwrap());
+                // The first request for page 2 fails with a transient error
+                let failed = cursors.len() == 2;
+                async move {
+                    if failed {
+                        return Err(Error::Api {
+                            status: 503,
+                            message: "unavailable".to_string(),
+                        });
+                    }
+                    fetch_page(params).await
+                }
+            },
+            RetryPolicy::new(3, std::time::Duration::ZERO, 1.0),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            *cursors.borrow(),
+            vec![INITIAL_CURSOR, "page2", "page2", "page3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_surfaces_error_after_attempts_exhausted() {
+        let attempts = std::cell::Cell::new(0);
+        let stream = paginate_all_with_retry(
+            |params: PaginationParams| {
+                let broken = params.next_cursor.as_deref() != Some(INITIAL_CURSOR);
+                if broken {
+                    attempts.set(attempts.get() + 1);
+                }
+                async move {
+                    match broken {
+                        false => Ok((vec![1], Some("broken".to_string()))),
+                        true => Err(Error::Api {
+                            status: 500,
+                            message: "boom".to_string(),
+                        }),
+                    }
+                }
+            },
+            RetryPolicy::new(3, std::time::Duration::ZERO, 1.0),
+        );
+
+        let results: Vec<Result<u32>> = stream.collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[1], Err(Error::Api { status: 500, .. })));
+        assert_eq!(attempts.get(), 3);
+    }
+
+    type Page = (Vec<u32>, Option<String>);
+
+    /// Fake endpoint that never answers past page 1, holding `guard` while pending
+    fn stalled_fetch(
+        guard: &std::sync::Arc<()>,
+    ) -> impl FnMut(PaginationParams) -> futures_util::future::BoxFuture<'static, Result<Page>>
+    {
+        let guard = guard.clone();
+        move |params| {
+            let guard = guard.clone();
+            Box::pin(async move {
+                if params.next_cursor.as_deref() != Some(INITIAL_CURSOR) {
+                    let _held = guard;
+                    std::future::pending::<()>().await;
+                }
+                fetch_page(params).await
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_until_returns_items_so_far() {
+        let guard = std::sync::Arc::new(());
+        let collected = collect_all_until(
+            CursorPagination,
+            stalled_fetch(&guard),
+            RetryPolicy::none(),
+            None,
+            tokio::time::sleep(std::time::Duration::from_millis(20)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(collected.items, vec![1, 2]);
+        assert!(collected.cancelled);
+        // The stalled page request was dropped along with the walk
+        assert_eq!(std::sync::Arc::strong_count(&guard), 1);
+
+        let collected = collect_all_until(
+            CursorPagination,
+            fetch_page,
+            RetryPolicy::none(),
+            Some(3),
+            std::future::pending(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(collected.items, vec![1, 2, 3]);
+        assert!(!collected.cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_dropping_stream_mid_flight_releases_fetch() {
+        let guard = std::sync::Arc::new(());
+        let mut stream = Box::pin(paginate_all(stalled_fetch(&guard)));
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+
+        // The request for page 2 is now pending
+        let pending = tokio::time::timeout(std::time::Duration::from_millis(20), stream.next());
+        assert!(pending.await.is_err());
+        assert_eq!(std::sync::Arc::strong_count(&guard), 3);
+
+        drop(stream);
+        assert_eq!(std::sync::Arc::strong_count(&guard), 1);
+    }
+
+    #[tokio::test]
+    async fn test_offset_pagination_stops_at_short_page() {
+        let offsets = std::cell::RefCell::new(Vec::new());
+        let items = collect_all_with(
+            OffsetPagination::new(2).with_offset(1),
+            |page: OffsetPagination| {
+                offsets.borrow_mut().push(page.offset);
+                // Five items in total, served from `offset`
+                let items: Vec<u32> = (page.offset..5).take(page.limit as usize).collect();
+                async move { Ok(items) }
+            },
+            RetryPolicy::none(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3, 4]);
+        assert_eq!(*offsets.borrow(), vec![1, 3, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_with_cursor_strategy() {
+        let items: Vec<u32> = paginate_with(CursorPagination, fetch_page, RetryPolicy::none())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn test_pagination_params() {
         let params = PaginationParams::initial();
@@ -53,4 +813,34 @@ mod tests {
         assert_eq!(query.len(), 1);
         assert_eq!(query[0].0, "next_cursor");
     }
+
+    #[test]
+    fn test_pagination_limit_bounds() {
+        let params = PaginationParams::initial().with_limit(100).unwrap();
+        assert_eq!(params.limit, Some(100));
+        assert_eq!(params.to_query_params()[1], ("limit", "100".to_string()));
+
+        assert!(PaginationParams::new().with_limit(MAX_PAGE_LIMIT).is_ok());
+        assert!(matches!(
+            PaginationParams::new().with_limit(0),
+            Err(Error::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            PaginationParams::new().with_limit(MAX_PAGE_LIMIT + 1),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_pagination_max_limit_override() {
+        let params = PaginationParams::new()
+            .with_max_limit(1000)
+            .with_limit(1000)
+            .unwrap();
+        assert_eq!(params.limit, Some(1000));
+
+        let mut params = PaginationParams::new().with_max_limit(50);
+        params.limit = Some(100);
+        assert!(params.validate().is_err());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::error::Result;
-use alloy_primitives::{hex::encode_prefixed, Address, U256};
-use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use alloy_primitives::{hex::encode_prefixed, Address, B256, U256};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct};
 
 // EIP-712 struct for CLOB authentication
 sol! {
@@ -66,6 +66,24 @@ where
     Ok(encode_prefixed(signature.as_bytes()))
 }
 
+/// EIP-712 domain of orders for the exchange `verifying_contract` on `chain_id`
+///
+/// The chain ID comes from the [`Network`](crate::config::Network), so an
+/// order signed for the Amoy testnet is not valid on mainnet and vice versa.
+pub fn order_domain(chain_id: u64, verifying_contract: Address) -> Eip712Domain {
+    eip712_domain!(
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: verifying_contract,
+    )
+}
+
+/// EIP-712 hash of `order`, which the exchange uses as the order ID
+pub fn order_hash(order: &Order, chain_id: u64, verifying_contract: Address) -> B256 {
+    order.eip712_signing_hash(&order_domain(chain_id, verifying_contract))
+}
+
 /// Signs an order using EIP-712
 ///
 /// This creates the signature for a limit or market order
@@ -79,17 +97,111 @@ pub fn sign_order_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
-    let domain = eip712_domain!(
-        name: "Polymarket CTF Exchange",
-        version: "1",
-        chain_id: chain_id,
-        verifying_contract: verifying_contract,
-    );
-
-    let hash = order.eip712_signing_hash(&domain);
+    let hash = order_hash(&order, chain_id, verifying_contract);
     let signature = signer
         .sign_hash_sync(&hash)
         .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, PrimitiveSignature};
+    use alloy_signer_local::PrivateKeySigner;
+
+    // Well-known development key (anvil/hardhat account #0)
+    const PRIVATE_KEY: &str = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn signer() -> PrivateKeySigner {
+        PRIVATE_KEY.parse().unwrap()
+    }
+
+    #[test]
+    fn test_order_type_matches_exchange() {
+        // Must match ORDER_TYPEHASH in the CTF Exchange contract field for field
+        assert_eq!(
+            Order::eip712_encode_type(),
+            "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,\
+             uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,\
+             uint256 feeRateBps,uint8 side,uint8 signatureType)"
+        );
+    }
+
+    #[test]
+    fn test_sign_clob_auth_message_known_vector() {
+        // Same vector as the reference Python client
+        let signature =
+            sign_clob_auth_message(&signer(), "10000000".to_string(), U256::from(23), 80002)
+                .unwrap();
+        assert_eq!(
+            signature,
+            "0xf62319a987514da40e57e2f4d7529f7bac38f0355bd88bb5adbb3768d80de6c1682518e0af677d5260366425f4361e7b70c25ae232aff0ab2331e2b164a1aedc1b"
+        );
+    }
+
+    #[test]
+    fn test_sign_order_message_recovers_signer() {
+        let signer = signer();
+        let exchange = address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+        let order = Order {
+            salt: U256::from(479249096354u64),
+            maker: signer.address(),
+            signer: signer.address(),
+            taker: Address::ZERO,
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(100_000_000u64),
+            takerAmount: U256::from(50_000_000u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::from(100),
+            side: 0,
+            signatureType: 0,
+        };
+        let domain = eip712_domain!(
+            name: "Polymarket CTF Exchange",
+            version: "1",
+            chain_id: 137,
+            verifying_contract: exchange,
+        );
+        let hash = order.eip712_signing_hash(&domain);
+
+        let signature = sign_order_message(&signer, order.clone(), 137, exchange).unwrap();
+        // Signing is deterministic (RFC 6979)
+        assert_eq!(
+            signature,
+            sign_order_message(&signer, order, 137, exchange).unwrap()
+        );
+
+        let bytes = alloy_primitives::hex::decode(&signature).unwrap();
+        let recovered = PrimitiveSignature::try_from(bytes.as_slice())
+            .unwrap()
+            .recover_address_from_prehash(&hash)
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn test_order_domain_separator_differs_per_network() {
+        use crate::config::Network;
+
+        let separator = |network: Network| {
+            let exchange: Address = network
+                .contract_config(false)
+                .unwrap()
+                .exchange
+                .parse()
+                .unwrap();
+            order_domain(network.chain_id(), exchange).separator()
+        };
+        assert_ne!(separator(Network::PolygonMainnet), separator(Network::Amoy));
+
+        // The chain ID alone changes the domain, even for the same contract
+        let exchange = address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
+        assert_ne!(
+            order_domain(137, exchange).separator(),
+            order_domain(80002, exchange).separator()
+        );
+    }
+}
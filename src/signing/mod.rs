@@ -1,5 +1,11 @@
 mod eip712;
+mod nonce;
 mod signer;
+mod wallet;
 
-pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
+pub use eip712::{
+    order_domain, order_hash, sign_clob_auth_message, sign_order_message, ClobAuth, Order,
+};
+pub use nonce::{get_onchain_nonce, NonceManager};
 pub use signer::EthSigner;
+pub use wallet::{funder_address, proxy_wallet_address, safe_wallet_address};
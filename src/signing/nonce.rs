@@ -0,0 +1,166 @@
+use crate::error::{Error, Result};
+use alloy_primitives::{hex, Address, U256};
+use alloy_sol_types::{sol, SolCall};
+use std::sync::Mutex;
+
+sol! {
+    function nonces(address maker) returns (uint256);
+}
+
+/// Tracks the order nonce of a maker
+///
+/// The exchange only accepts orders whose nonce equals the maker's current
+/// on-chain nonce; calling `incrementNonce` on the exchange invalidates every
+/// order signed with an older one. Orders signed with a stale nonce are
+/// rejected without a clear error, so keep this in sync with the chain:
+/// [`sync`](Self::sync) on startup and [`increment`](Self::increment) after
+/// incrementing the nonce on-chain.
+///
+/// Attach it to an [`OrderBuilder`](crate::orders::OrderBuilder) with
+/// [`with_nonce_manager`](crate::orders::OrderBuilder::with_nonce_manager) to
+/// sign every order with the current nonce.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    nonce: Mutex<U256>,
+}
+
+impl NonceManager {
+    /// Create a manager starting at `nonce`
+    pub fn new(nonce: U256) -> Self {
+        Self {
+            nonce: Mutex::new(nonce),
+        }
+    }
+
+    /// Nonce to sign new orders with
+    pub fn current(&self) -> U256 {
+        *self.nonce.lock().unwrap()
+    }
+
+    /// Advance to the next nonce and return it
+    ///
+    /// Call after `incrementNonce` succeeded on-chain.
+    pub fn increment(&self) -> U256 {
+        let mut nonce = self.nonce.lock().unwrap();
+        *nonce += U256::from(1);
+        *nonce
+    }
+
+    /// Set the nonce, e.g. to a value read from the chain
+    pub fn reset(&self, nonce: U256) {
+        *self.nonce.lock().unwrap() = nonce;
+    }
+
+    /// Read the on-chain nonce of `maker` and adopt it
+    ///
+    /// # Arguments
+    /// * `rpc_url` - JSON-RPC endpoint of a Polygon node
+    /// * `exchange` - The exchange contract the orders are signed for
+    /// * `maker` - The address funding the orders
+    pub async fn sync(&self, rpc_url: &str, exchange: Address, maker: Address) -> Result<U256> {
+        let nonce = get_onchain_nonce(rpc_url, exchange, maker).await?;
+        self.reset(nonce);
+        Ok(nonce)
+    }
+}
+
+/// Read the current order nonce of `maker` from the exchange contract
+///
+/// # Arguments
+/// * `rpc_url` - JSON-RPC endpoint of a Polygon node
+/// * `exchange` - The exchange contract (see [`get_contract_config`](crate::config::get_contract_config))
+/// * `maker` - The address funding the orders
+pub async fn get_onchain_nonce(rpc_url: &str, exchange: Address, maker: Address) -> Result<U256> {
+    let call = noncesCall { maker };
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_call",
+        "params": [
+            { "to": exchange.to_checksum(None), "data": hex::encode_prefixed(call.abi_encode()) },
+            "latest"
+        ],
+    });
+
+    let response = reqwest::Client::new()
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await?;
+    let status = response.status().as_u16();
+    let value: serde_json::Value = response.json().await?;
+
+    if let Some(error) = value.get("error") {
+        return Err(Error::Api {
+            status,
+            message: format!("eth_call failed: {}", error),
+        });
+    }
+    let result = value["result"]
+        .as_str()
+        .ok_or_else(|| Error::MissingField("result".to_string()))?;
+    let data = hex::decode(result)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid eth_call result: {}", e)))?;
+
+    noncesCall::abi_decode_returns(&data, true)
+        .map(|decoded| decoded._0)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid eth_call result: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::{MockResponse, TestServer};
+
+    #[test]
+    fn test_increment_and_reset() {
+        let manager = NonceManager::default();
+        assert_eq!(manager.current(), U256::ZERO);
+        assert_eq!(manager.increment(), U256::from(1));
+        assert_eq!(manager.current(), U256::from(1));
+
+        manager.reset(U256::from(7));
+        assert_eq!(manager.current(), U256::from(7));
+    }
+
+    #[tokio::test]
+    async fn test_sync_reads_onchain_nonce() {
+        let result = format!("0x{:064x}", 5);
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            &format!(r#"{{"jsonrpc":"2.0","id":1,"result":"{}"}}"#, result),
+        )])
+        .await;
+        let maker = Address::repeat_byte(0x11);
+
+        let manager = NonceManager::default();
+        let nonce = manager
+            .sync(&server.url, Address::repeat_byte(0x22), maker)
+            .await
+            .unwrap();
+        assert_eq!(nonce, U256::from(5));
+        assert_eq!(manager.current(), U256::from(5));
+
+        let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+        let data = body["params"][0]["data"].as_str().unwrap();
+        // nonces(address) selector followed by the padded maker address
+        assert!(data.starts_with("0x7ecebe00"), "{}", data);
+        assert!(data.ends_with(&"11".repeat(20)), "{}", data);
+    }
+
+    #[tokio::test]
+    async fn test_sync_reports_rpc_errors() {
+        let server = TestServer::start(vec![MockResponse::json(
+            200,
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"execution reverted"}}"#,
+        )])
+        .await;
+
+        let manager = NonceManager::new(U256::from(3));
+        let result = manager
+            .sync(&server.url, Address::ZERO, Address::ZERO)
+            .await;
+        assert!(matches!(result, Err(Error::Api { .. })));
+        assert_eq!(manager.current(), U256::from(3));
+    }
+}
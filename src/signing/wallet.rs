@@ -0,0 +1,117 @@
+use crate::config::get_wallet_config;
+use crate::error::{Error, Result};
+use crate::types::SignatureType;
+use alloy_primitives::{b256, keccak256, Address, B256};
+use std::str::FromStr;
+
+/// Hash of the init code the proxy factory deploys wallets with
+const PROXY_INIT_CODE_HASH: B256 =
+    b256!("d21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b");
+
+/// Hash of the init code the Safe factory deploys wallets with
+const SAFE_INIT_CODE_HASH: B256 =
+    b256!("2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf");
+
+/// Address of the Polymarket proxy wallet owned by `owner`
+///
+/// Proxy wallets belong to accounts created with an email (Magic) login.
+/// The address is the CREATE2 address of the proxy factory, salted with the
+/// owner's address, so it is known before the wallet is deployed.
+///
+/// # Errors
+/// Returns [`Error::Config`] if the chain has no proxy factory.
+pub fn proxy_wallet_address(owner: Address, chain_id: u64) -> Result<Address> {
+    let factory = get_wallet_config(chain_id)?.proxy_factory.ok_or_else(|| {
+        Error::Config(format!("No proxy wallet factory on chain_id {}", chain_id))
+    })?;
+    let salt = keccak256(owner.as_slice());
+    Ok(parse_factory(&factory)?.create2(salt, PROXY_INIT_CODE_HASH))
+}
+
+/// Address of the Gnosis Safe owned by `owner`
+///
+/// Safes belong to accounts created with a browser wallet. The address is
+/// the CREATE2 address of the Safe factory, salted with the ABI-encoded
+/// owner address.
+///
+/// # Errors
+/// Returns [`Error::Config`] if the chain is not supported.
+pub fn safe_wallet_address(owner: Address, chain_id: u64) -> Result<Address> {
+    let factory = get_wallet_config(chain_id)?.safe_factory;
+    let salt = keccak256(owner.into_word());
+    Ok(parse_factory(&factory)?.create2(salt, SAFE_INIT_CODE_HASH))
+}
+
+/// Address that makes and funds the orders signed by `signer` with `sig_type`
+///
+/// The signer itself for [`SignatureType::Eoa`], otherwise its proxy wallet
+/// or Safe.
+pub fn funder_address(sig_type: SignatureType, signer: Address, chain_id: u64) -> Result<Address> {
+    match sig_type {
+        SignatureType::Eoa => Ok(signer),
+        SignatureType::PolyProxy => proxy_wallet_address(signer, chain_id),
+        SignatureType::PolyGnosisSafe => safe_wallet_address(signer, chain_id),
+    }
+}
+
+fn parse_factory(factory: &str) -> Result<Address> {
+    Address::from_str(factory)
+        .map_err(|e| Error::Config(format!("Invalid wallet factory address: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_wallet_addresses_per_signature_type() {
+        // Computed independently from the CREATE2 formula,
+        // keccak256(0xff ++ factory ++ salt ++ init_code_hash)[12..]
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        let proxy = address!("365f0CA36Ae1f641E02fE3B7743673da42A13A70");
+        let safe = address!("d93B25cb943D14d0d34FBaF01Fc93a0f8b5F6E47");
+
+        assert_eq!(
+            funder_address(SignatureType::Eoa, owner, 137).unwrap(),
+            owner
+        );
+        assert_eq!(
+            funder_address(SignatureType::PolyProxy, owner, 137).unwrap(),
+            proxy
+        );
+        assert_eq!(
+            funder_address(SignatureType::PolyGnosisSafe, owner, 137).unwrap(),
+            safe
+        );
+    }
+
+    #[test]
+    fn test_wallet_salt_encodings() {
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        // Proxy: the packed 20-byte address
+        assert_eq!(
+            keccak256(owner.as_slice()),
+            b256!("e9707d0e6171f728f7473c24cc0432a9b07eaaf1efed6a137a4a8c12c79552d9")
+        );
+        // Safe: the address ABI-encoded as a 32-byte word
+        assert_eq!(
+            keccak256(owner.into_word()),
+            b256!("d9c5115d8ca09413513b0348ccd4aa5d5d2b8183823763b527bfd81f40d86f2a")
+        );
+    }
+
+    #[test]
+    fn test_wallet_factories_per_chain() {
+        let owner = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        assert!(safe_wallet_address(owner, 80002).is_ok());
+        assert!(matches!(
+            proxy_wallet_address(owner, 80002),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            safe_wallet_address(owner, 999),
+            Err(Error::Config(_))
+        ));
+    }
+}
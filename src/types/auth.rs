@@ -1,4 +1,10 @@
+use crate::error::Result;
+use crate::utils::get_current_unix_time_secs;
+use alloy_primitives::Address;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// API credentials for L2 authentication
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -19,6 +25,54 @@ impl ApiCreds {
     }
 }
 
+/// API credentials together with the wallet address they belong to
+///
+/// Produces the L2 headers (`POLY_ADDRESS`, `POLY_SIGNATURE`, `POLY_TIMESTAMP`,
+/// `POLY_API_KEY`, `POLY_PASSPHRASE`) for an authenticated request.
+#[derive(Debug, Clone)]
+pub struct L2Credentials {
+    pub address: Address,
+    pub creds: ApiCreds,
+}
+
+impl L2Credentials {
+    pub fn new(address: Address, creds: ApiCreds) -> Self {
+        Self { address, creds }
+    }
+
+    /// Build the L2 headers for a request signed at the current time
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method, e.g. `"GET"`
+    /// * `req_path` - Request path without the query string, e.g. `"/orders"`
+    /// * `body` - Request body, serialized as compact JSON before signing
+    pub fn headers<T>(
+        &self,
+        method: &str,
+        req_path: &str,
+        body: Option<&T>,
+    ) -> Result<HashMap<&'static str, String>>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.headers_at(get_current_unix_time_secs()?, method, req_path, body)
+    }
+
+    /// Build the L2 headers for a request signed at `timestamp` (Unix seconds)
+    pub fn headers_at<T>(
+        &self,
+        timestamp: u64,
+        method: &str,
+        req_path: &str,
+        body: Option<&T>,
+    ) -> Result<HashMap<&'static str, String>>
+    where
+        T: ?Sized + Serialize,
+    {
+        crate::http::build_l2_headers(self.address, &self.creds, timestamp, method, req_path, body)
+    }
+}
+
 /// Response from API keys list endpoint
 #[derive(Debug, Deserialize)]
 pub struct ApiKeysResponse {
@@ -39,6 +93,18 @@ impl BalanceAllowanceParams {
         Self::default()
     }
 
+    /// Query the USDC collateral balance
+    pub fn collateral() -> Self {
+        Self::new().asset_type(super::AssetType::Collateral)
+    }
+
+    /// Query the balance of one outcome token
+    pub fn conditional(token_id: impl Into<String>) -> Self {
+        Self::new()
+            .asset_type(super::AssetType::Conditional)
+            .token_id(token_id)
+    }
+
     pub fn asset_type(mut self, asset_type: super::AssetType) -> Self {
         self.asset_type = Some(asset_type);
         self
@@ -76,3 +142,113 @@ impl BalanceAllowanceParams {
         params
     }
 }
+
+/// Balance and allowance of the account for one asset
+///
+/// The API reports 6-decimal base units; amounts here are converted to USDC
+/// for collateral and to shares for conditional tokens. Unlimited approvals
+/// (the maximum uint256) don't fit a `Decimal` and are reported as
+/// [`Decimal::MAX`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawBalanceAllowance")]
+pub struct BalanceAllowance {
+    pub balance: Decimal,
+    /// Smallest allowance granted to the exchange contracts, which bounds
+    /// what an order can spend
+    pub allowance: Decimal,
+    /// Allowance per spender contract, when reported separately
+    pub allowances: HashMap<String, Decimal>,
+}
+
+#[derive(Deserialize)]
+struct RawBalanceAllowance {
+    balance: String,
+    #[serde(default)]
+    allowance: Option<String>,
+    #[serde(default)]
+    allowances: HashMap<String, String>,
+}
+
+impl TryFrom<RawBalanceAllowance> for BalanceAllowance {
+    type Error = String;
+
+    fn try_from(raw: RawBalanceAllowance) -> std::result::Result<Self, Self::Error> {
+        let allowances = raw
+            .allowances
+            .iter()
+            .map(|(spender, amount)| Ok((spender.clone(), from_base_units(amount)?)))
+            .collect::<std::result::Result<HashMap<_, _>, String>>()?;
+        let allowance = match raw.allowance {
+            Some(amount) => from_base_units(&amount)?,
+            None => allowances.values().min().copied().unwrap_or_default(),
+        };
+
+        Ok(Self {
+            balance: from_base_units(&raw.balance)?,
+            allowance,
+            allowances,
+        })
+    }
+}
+
+/// Parse an integer amount in 6-decimal base units, saturating at `Decimal::MAX`
+fn from_base_units(amount: &str) -> std::result::Result<Decimal, String> {
+    if !amount.is_empty() && amount.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(match Decimal::from_str(amount) {
+            Ok(units) => (units / Decimal::from(1_000_000)).normalize(),
+            Err(_) => Decimal::MAX,
+        });
+    }
+    Err(format!("invalid token amount: {:?}", amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    #[test]
+    fn test_balance_allowance_base_units() {
+        let json = r#"{"balance":"12500000","allowances":{
+            "0xexchange":"115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            "0xnegrisk":"5000000"}}"#;
+        let balance: BalanceAllowance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.balance, Decimal::new(125, 1));
+        assert_eq!(balance.allowance, Decimal::from(5));
+        assert_eq!(balance.allowances["0xexchange"], Decimal::MAX);
+
+        let single: BalanceAllowance =
+            serde_json::from_str(r#"{"balance":"0","allowance":"1"}"#).unwrap();
+        assert_eq!(single.allowance, Decimal::new(1, 6));
+        assert!(serde_json::from_str::<BalanceAllowance>(r#"{"balance":"-1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_l2_credentials_headers() {
+        let creds = L2Credentials::new(
+            address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+            ApiCreds::new(
+                "key".to_string(),
+                "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string(),
+                "pass".to_string(),
+            ),
+        );
+        let body = HashMap::from([("hash", "0x123")]);
+
+        let headers = creds
+            .headers_at(1000000, "POST", "/order", Some(&body))
+            .unwrap();
+
+        assert_eq!(
+            headers["POLY_SIGNATURE"],
+            "OOTE36agp5eJItx_SsJTrPH0CNXxF-4trbM6TzyRb80="
+        );
+        assert_eq!(
+            headers["POLY_ADDRESS"],
+            "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"
+        );
+        assert_eq!(headers["POLY_TIMESTAMP"], "1000000");
+        assert_eq!(headers["POLY_API_KEY"], "key");
+        assert_eq!(headers["POLY_PASSPHRASE"], "pass");
+    }
+}
@@ -9,7 +9,7 @@ pub enum AssetType {
 }
 
 /// Order side (BUY or SELL)
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     #[default]
@@ -47,23 +47,34 @@ impl Side {
 }
 
 /// Order type
+///
+/// GTC and GTD orders rest on the book until filled, canceled or expired.
+/// FOK and FAK orders only take liquidity: whatever can't be matched
+/// immediately is canceled, so they never rest on the book.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderType {
     /// Good till canceled
     #[serde(rename = "GTC")]
     Gtc,
-    /// Fill or kill (must be fully filled)
+    /// Fill or kill: filled entirely right away, or canceled without any fill
     #[serde(rename = "FOK")]
     Fok,
-    /// Fill and kill (can be partially filled)
+    /// Fill and kill: fills as much as possible right away and cancels the rest
     #[serde(rename = "FAK")]
     Fak,
-    /// Good till date
+    /// Good till date: rests until the expiration of the signed order
     #[serde(rename = "GTD")]
     Gtd,
 }
 
+impl OrderType {
+    /// Whether orders of this type are matched immediately and never rest (FOK, FAK)
+    pub fn is_immediate(self) -> bool {
+        matches!(self, OrderType::Fok | OrderType::Fak)
+    }
+}
+
 /// Signature type for orders
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignatureType {
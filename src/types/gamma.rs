@@ -28,6 +28,11 @@ pub struct GammaMarket {
     pub slug: String,
     pub category: Option<String>,
     pub market_type: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_datetime"
+    )]
+    pub end_date: Option<DateTime<Utc>>,
 
     // Trading data as strings to avoid parsing issues
     pub volume: Option<String>,
@@ -106,6 +111,8 @@ pub struct GammaEvent {
     pub ticker: String,
     pub slug: String,
     pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
 
     // Dates
     #[serde(
@@ -184,6 +191,7 @@ pub struct GammaEvent {
     pub volume1yr: Option<f64>,
     pub liquidity_amm: Option<f64>,
 
+    #[serde(default)]
     pub markets: Vec<GammaMarket>,
 }
 
@@ -3,6 +3,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Full market information
+///
+/// Fields the CLOB may omit are `None` when absent, and unknown fields are
+/// ignored.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
     pub condition_id: String,
@@ -12,10 +15,13 @@ pub struct Market {
     pub max_incentive_spread: Option<String>,
     pub active: bool,
     pub closed: bool,
-    pub enable_order_book: bool,
+    pub enable_order_book: Option<bool>,
     pub archived: bool,
     pub accepting_orders: bool,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_optional_datetime")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_datetime"
+    )]
     pub accepting_order_timestamp: Option<DateTime<Utc>>,
     pub question_id: String,
     pub question: String,
@@ -23,18 +29,24 @@ pub struct Market {
     pub minimum_order_size: Decimal,
     #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub minimum_tick_size: Decimal,
-    pub description: String,
+    pub description: Option<String>,
     pub category: Option<String>,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_optional_datetime")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_datetime"
+    )]
     pub end_date_iso: Option<DateTime<Utc>>,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_optional_datetime")]
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_datetime"
+    )]
     pub game_start_time: Option<DateTime<Utc>>,
-    pub market_slug: String,
-    pub icon: String,
-    pub fpmm: String,
-    pub neg_risk: bool,
-    pub neg_risk_market_id: String,
-    pub neg_risk_request_id: String,
+    pub market_slug: Option<String>,
+    pub icon: Option<String>,
+    pub fpmm: Option<String>,
+    pub neg_risk: Option<bool>,
+    pub neg_risk_market_id: Option<String>,
+    pub neg_risk_request_id: Option<String>,
 }
 
 impl Market {
@@ -156,6 +168,30 @@ pub struct NegRiskResponse {
     pub neg_risk: bool,
 }
 
+/// Fee rate response
+#[derive(Debug, Deserialize)]
+pub struct FeeRateResponse {
+    /// Fee rate in basis points
+    pub base_fee: u32,
+}
+
+/// Fee rates of a market in basis points, see
+/// [`ClobClient::get_fee_rate`](crate::client::ClobClient::get_fee_rate)
+///
+/// Pass the rate of the side the order takes to
+/// [`estimate_order_cost`](crate::orders::estimate_order_cost).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeRate {
+    /// Rate for orders that rest on the book
+    pub maker_bps: u32,
+    /// Rate for orders that fill against the book
+    pub taker_bps: u32,
+    /// Whether the rates are the
+    /// [`DEFAULT_FEE_RATE_BPS`](crate::orders::DEFAULT_FEE_RATE_BPS) fallback
+    /// rather than the market's own
+    pub estimated: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,7 +219,7 @@ mod tests {
             max_incentive_spread: None,
             active: true,
             closed: false,
-            enable_order_book: true,
+            enable_order_book: Some(true),
             archived: false,
             accepting_orders: true,
             accepting_order_timestamp: Some(
@@ -195,16 +231,16 @@ mod tests {
             question: "Test question?".to_string(),
             minimum_order_size: Decimal::ZERO,
             minimum_tick_size: Decimal::ZERO,
-            description: "Test".to_string(),
+            description: Some("Test".to_string()),
             category: None,
             end_date_iso,
             game_start_time: None,
-            market_slug: "test-market".to_string(),
-            icon: "".to_string(),
-            fpmm: "0x0".to_string(),
-            neg_risk: false,
-            neg_risk_market_id: "".to_string(),
-            neg_risk_request_id: "".to_string(),
+            market_slug: Some("test-market".to_string()),
+            icon: Some("".to_string()),
+            fpmm: Some("0x0".to_string()),
+            neg_risk: Some(false),
+            neg_risk_market_id: Some("".to_string()),
+            neg_risk_request_id: Some("".to_string()),
         }
     }
 
@@ -243,4 +279,42 @@ mod tests {
         assert!(market.ends_within(TimeDelta::hours(1)));
         assert!(market.ends_within(TimeDelta::days(7)));
     }
+
+    #[test]
+    fn test_deserialize_market_with_missing_fields() {
+        let json = r#"{
+            "condition_id": "0xabc",
+            "tokens": [
+                {"token_id": "1", "outcome": "Yes"},
+                {"token_id": "2", "outcome": "No"}
+            ],
+            "rewards": {"rates": null, "min_size": 0, "max_spread": 0},
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "question_id": "0xdef",
+            "question": "Will it rain?",
+            "minimum_order_size": 5,
+            "minimum_tick_size": 0.01,
+            "some_new_field": {"nested": true}
+        }"#;
+
+        let market: Market = serde_json::from_str(json).unwrap();
+        assert_eq!(market.condition_id, "0xabc");
+        assert_eq!(market.minimum_tick_size, Decimal::new(1, 2));
+        assert!(market.end_date_iso.is_none());
+        assert!(market.accepting_order_timestamp.is_none());
+        assert_eq!(market.neg_risk, None);
+        assert_eq!(market.market_slug, None);
+
+        // An empty value stays distinguishable from a missing one
+        let json = json.replace(
+            r#""active""#,
+            r#""neg_risk": false, "market_slug": "", "active""#,
+        );
+        let market: Market = serde_json::from_str(&json).unwrap();
+        assert_eq!(market.neg_risk, Some(false));
+        assert_eq!(market.market_slug.as_deref(), Some(""));
+    }
 }
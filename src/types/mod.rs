@@ -4,6 +4,7 @@ mod gamma;
 mod market;
 mod order;
 mod primitives;
+mod rewards;
 mod serde_helpers;
 mod trade;
 mod websocket;
@@ -15,6 +16,7 @@ pub use gamma::*;
 pub use market::*;
 pub use order::*;
 pub use primitives::*;
+pub use rewards::*;
 pub use trade::*;
 pub use websocket::*;
 
@@ -1,9 +1,12 @@
 use super::enums::{OrderType, Side};
-use crate::error::Result;
-use crate::{orders::calculate_market_price, OrderId};
-use alloy_primitives::U256;
+use crate::error::{Error, Result};
+use crate::orders::{best_ask, best_bid, calculate_market_price, is_crossing, mid_price, spread};
+use crate::signing::Order;
+use crate::OrderId;
+use alloy_primitives::{Address, U256};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 
@@ -126,6 +129,41 @@ pub struct SignedOrderRequest {
     pub signature: String,
 }
 
+impl SignedOrderRequest {
+    /// The EIP-712 struct this order was signed as
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidOrder`] if a field doesn't parse.
+    pub fn to_order(&self) -> Result<Order> {
+        let invalid =
+            |field: &str| Error::InvalidOrder(format!("Invalid {} in signed order", field));
+        let uint =
+            |value: &str, field: &str| U256::from_str_radix(value, 10).map_err(|_| invalid(field));
+        let address =
+            |value: &str, field: &str| value.parse::<Address>().map_err(|_| invalid(field));
+        let side = match self.side.as_str() {
+            "BUY" => Side::Buy,
+            "SELL" => Side::Sell,
+            _ => return Err(invalid("side")),
+        };
+
+        Ok(Order {
+            salt: U256::from(self.salt),
+            maker: address(&self.maker, "maker")?,
+            signer: address(&self.signer, "signer")?,
+            taker: address(&self.taker, "taker")?,
+            tokenId: uint(&self.token_id, "token_id")?,
+            makerAmount: uint(&self.maker_amount, "maker_amount")?,
+            takerAmount: uint(&self.taker_amount, "taker_amount")?,
+            expiration: uint(&self.expiration, "expiration")?,
+            nonce: uint(&self.nonce, "nonce")?,
+            feeRateBps: uint(&self.fee_rate_bps, "fee_rate_bps")?,
+            side: side.to_u8(),
+            signatureType: self.signature_type,
+        })
+    }
+}
+
 /// Order to be posted to the API
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -179,12 +217,25 @@ pub struct OpenOrder {
     pub created_at: u64,
 }
 
+impl OpenOrder {
+    /// Whether the order can no longer change: fully matched, canceled, expired or invalid
+    pub fn is_terminal(&self) -> bool {
+        let status = self.status.to_ascii_uppercase();
+        let status = status.trim_start_matches("ORDER_STATUS_");
+        matches!(status, "MATCHED" | "CANCELED" | "EXPIRED" | "INVALID")
+            || status.starts_with("CANCELED")
+            || (self.original_size > Decimal::ZERO && self.size_matched >= self.original_size)
+    }
+}
+
 /// Parameters for querying open orders
 #[derive(Debug, Clone, Default)]
 pub struct OpenOrderParams {
     pub id: Option<String>,
     pub asset_id: Option<String>,
     pub market: Option<String>,
+    /// Only keep orders on this side (filtered client-side)
+    pub side: Option<Side>,
 }
 
 impl OpenOrderParams {
@@ -207,6 +258,11 @@ impl OpenOrderParams {
         self
     }
 
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, &String)> {
         let mut params = Vec::with_capacity(3);
 
@@ -227,16 +283,38 @@ impl OpenOrderParams {
 }
 
 /// Price level in order book (price and size pair)
+///
+/// Prices and sizes are serialized as strings, matching the API, and accept
+/// either strings or numbers when deserializing.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct PriceLevel {
     /// Price at this level
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
     pub price: Decimal,
     /// Total size available at this price
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
     pub size: Decimal,
 }
 
+impl PriceLevel {
+    pub fn new(price: Decimal, size: Decimal) -> Self {
+        Self { price, size }
+    }
+}
+
+impl From<(Decimal, Decimal)> for PriceLevel {
+    /// Convert a `(price, size)` pair
+    fn from((price, size): (Decimal, Decimal)) -> Self {
+        Self::new(price, size)
+    }
+}
+
 /// Order book summary with bids and asks
 #[derive(Debug, Deserialize)]
 pub struct OrderBookSummary {
@@ -265,17 +343,103 @@ impl OrderBookSummary {
         self.bids.is_empty() && self.asks.is_empty()
     }
 
+    /// Whether a limit order at `price` would cross this book, see [`is_crossing`]
+    pub fn is_crossing(&self, price: Decimal, side: Side) -> bool {
+        is_crossing(price, side, best_bid(&self.bids), best_ask(&self.asks))
+    }
+
     pub fn sort_bids(&self) -> Vec<PriceLevel> {
         let mut bids = self.bids.clone();
-        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        bids.sort_by_key(|level| std::cmp::Reverse(level.price));
         bids
     }
 
     pub fn sort_asks(&self) -> Vec<PriceLevel> {
         let mut asks = self.asks.clone();
-        asks.sort_by(|a, b| a.price.cmp(&b.price));
+        asks.sort_by_key(|level| level.price);
         asks
     }
+
+    /// Sort in place so bids are descending and asks ascending (best price first)
+    pub fn sort(&mut self) {
+        self.bids.sort_by_key(|level| std::cmp::Reverse(level.price));
+        self.asks.sort_by_key(|level| level.price);
+    }
+
+    /// Hash of this book as computed by Polymarket, see [`compute_book_hash`]
+    ///
+    /// [`compute_book_hash`]: crate::orders::compute_book_hash
+    pub fn compute_hash(&self) -> String {
+        crate::orders::compute_book_hash(
+            &self.market,
+            &self.asset_id,
+            self.timestamp,
+            &self.bids,
+            &self.asks,
+        )
+    }
+
+    /// Whether the levels match the `hash` reported with the book
+    ///
+    /// The server hashes the levels in the order it sends them: bids from the
+    /// lowest price up and asks from the highest down. A book in that order
+    /// or sorted with [`sort`](Self::sort), like the ones returned by
+    /// [`ClobClient::get_order_book`](crate::client::ClobClient::get_order_book),
+    /// verifies; a mismatch means levels were changed, added or removed, e.g.
+    /// by [`truncate`](Self::truncate).
+    pub fn verify_hash(&self) -> bool {
+        if self.compute_hash() == self.hash {
+            return true;
+        }
+
+        let mut bids = self.bids.clone();
+        bids.sort_by_key(|level| level.price);
+        let mut asks = self.asks.clone();
+        asks.sort_by_key(|level| std::cmp::Reverse(level.price));
+        let hash = crate::orders::compute_book_hash(
+            &self.market,
+            &self.asset_id,
+            self.timestamp,
+            &bids,
+            &asks,
+        );
+        hash == self.hash
+    }
+
+    /// Keep only the best `depth` levels on each side, sorting the book first
+    pub fn truncate(&mut self, depth: usize) {
+        self.sort();
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+}
+
+/// Order book with its best prices, see
+/// [`ClobClient::get_order_book_summary`](crate::client::ClobClient::get_order_book_summary)
+///
+/// The derived prices are `None` when the side they need is empty.
+#[derive(Debug)]
+pub struct OrderBookStats {
+    pub book: OrderBookSummary,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+    /// Midpoint of the best bid and ask
+    pub mid: Option<Decimal>,
+    /// Best ask minus best bid
+    pub spread: Option<Decimal>,
+}
+
+impl OrderBookStats {
+    /// Derive the best prices of `book`
+    pub fn new(book: OrderBookSummary) -> Self {
+        Self {
+            best_bid: best_bid(&book.bids),
+            best_ask: best_ask(&book.asks),
+            mid: mid_price(&book.bids, &book.asks),
+            spread: spread(&book.bids, &book.asks),
+            book,
+        }
+    }
 }
 
 /// Parameters for querying order book
@@ -295,16 +459,45 @@ impl BookParams {
 }
 
 /// Response from posting an order
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostOrderResponse {
+    #[serde(default)]
     pub error_msg: String,
     #[serde(rename = "orderID")]
     pub order_id: OrderId,
+    /// `live`, `matched`, `delayed` or `unmatched`
+    #[serde(default)]
     pub status: String,
     pub success: bool,
+    #[serde(default)]
+    pub making_amount: Option<String>,
+    #[serde(default)]
+    pub taking_amount: Option<String>,
+    /// Settlement transaction hashes, if the order matched immediately
+    #[serde(default, alias = "transactionHashes")]
+    pub transactions_hashes: Vec<String>,
+}
+
+impl PostOrderResponse {
+    /// Turn a `success: false` response into an error
+    ///
+    /// The reason in `error_msg` becomes [`Error::OrderRejected`], or
+    /// [`Error::InsufficientBalance`] for balance/allowance failures.
+    pub fn into_result(self) -> Result<Self> {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(Error::order_rejected(self.error_msg))
+        }
+    }
 }
 
+/// Outcome of one order in a batch post
+///
+/// `Ok` carries the accepted order, `Err` the reason it was rejected.
+pub type OrderResult = Result<PostOrderResponse>;
+
 /// Arguments for posting multiple orders
 #[derive(Debug, Clone)]
 pub struct PostOrderArgs {
@@ -321,12 +514,80 @@ impl PostOrderArgs {
 /// Response from canceling orders
 ///
 /// This response is returned by:
-/// - `cancel` - Cancel a single order
+/// - `cancel_order` - Cancel a single order
 /// - `cancel_orders` - Cancel multiple orders
-/// - `cancel_all` - Cancel all orders
+/// - `cancel_all` - Cancel all orders, optionally for one market
 /// - `cancel_market_orders` - Cancel orders by market/asset
-#[derive(Debug, Deserialize)]
+/// - `cancel_asset_orders` - Cancel orders for one token
+#[derive(Debug, Default, Deserialize)]
 pub struct CancelOrdersResponse {
+    /// Orders that were canceled
+    #[serde(default, deserialize_with = "super::serde_helpers::deserialize_null_default")]
     pub canceled: Vec<OrderId>,
-    pub not_canceled: serde_json::Value,
+    /// Orders that could not be canceled, with the reason
+    /// (e.g. already filled or canceled)
+    #[serde(default, deserialize_with = "super::serde_helpers::deserialize_null_default")]
+    pub not_canceled: HashMap<OrderId, String>,
+}
+
+impl CancelOrdersResponse {
+    /// Whether `order_id` was canceled by this request
+    pub fn is_canceled(&self, order_id: &OrderId) -> bool {
+        self.canceled.contains(order_id)
+    }
+
+    /// Why `order_id` could not be canceled, if it was reported as not canceled
+    pub fn not_canceled_reason(&self, order_id: &OrderId) -> Option<&str> {
+        self.not_canceled.get(order_id).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_book_sort() {
+        let mut book = OrderBookSummary {
+            market: "m".to_string(),
+            asset_id: "a".to_string(),
+            hash: "h".to_string(),
+            timestamp: 0,
+            bids: vec![(dec!(0.40), dec!(1)).into(), (dec!(0.45), dec!(1)).into()],
+            asks: vec![(dec!(0.60), dec!(1)).into(), (dec!(0.55), dec!(1)).into()],
+        };
+
+        book.sort();
+        assert_eq!(book.bids[0].price, dec!(0.45));
+        assert_eq!(book.asks[0].price, dec!(0.55));
+    }
+
+    #[test]
+    fn test_price_level_constructors() {
+        let level = PriceLevel::new(dec!(0.50), dec!(100));
+        assert_eq!(level.price, dec!(0.50));
+        assert_eq!(level.size, dec!(100));
+
+        let from_tuple: PriceLevel = (dec!(0.50), dec!(100)).into();
+        assert_eq!(from_tuple.price, level.price);
+        assert_eq!(from_tuple.size, level.size);
+    }
+
+    #[test]
+    fn test_price_level_deserialize_string_or_number() {
+        let level: PriceLevel = serde_json::from_str(r#"{"price":"0.52","size":"100"}"#).unwrap();
+        assert_eq!(level.price, dec!(0.52));
+        assert_eq!(level.size, dec!(100));
+
+        let level: PriceLevel = serde_json::from_str(r#"{"price":0.52,"size":100}"#).unwrap();
+        assert_eq!(level.price, dec!(0.52));
+        assert_eq!(level.size, dec!(100));
+
+        assert!(serde_json::from_str::<PriceLevel>(r#"{"price":"0.52"}"#).is_err());
+        assert!(serde_json::from_str::<PriceLevel>(r#"{"price":"abc","size":"1"}"#).is_err());
+
+        let json = serde_json::to_string(&PriceLevel::new(dec!(0.52), dec!(100))).unwrap();
+        assert_eq!(json, r#"{"price":"0.52","size":"100"}"#);
+    }
 }
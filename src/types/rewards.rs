@@ -0,0 +1,161 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Liquidity rewards program of one market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketReward {
+    pub condition_id: String,
+    /// Maximum distance from the midpoint, in cents, for an order to score
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rewards_max_spread: Decimal,
+    /// Minimum order size, in shares, for an order to score
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rewards_min_size: Decimal,
+    /// Reward epochs, one per reward asset
+    #[serde(default)]
+    pub rewards_config: Vec<RewardsConfig>,
+}
+
+/// One reward epoch of a market
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardsConfig {
+    /// Token the rewards are paid in
+    pub asset_address: String,
+    /// First day of the epoch (`YYYY-MM-DD`)
+    pub start_date: String,
+    /// Last day of the epoch (`YYYY-MM-DD`)
+    pub end_date: String,
+    /// Rewards paid out per day across all market makers
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub rate_per_day: Decimal,
+    /// Rewards paid out over the whole epoch
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
+    pub total_rewards: Decimal,
+}
+
+/// Paginated rewards response
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RewardsResponse {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<MarketReward>,
+}
+
+/// Parameters for querying reward programs
+///
+/// The rewards endpoints take no date or epoch filter: a market's past and
+/// current epochs all come back in [`MarketReward::rewards_config`].
+#[derive(Debug, Default, Clone)]
+pub struct RewardsParams {
+    pub condition_id: Option<String>,
+}
+
+impl RewardsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return the rewards of this market, including past epochs
+    pub fn condition_id(mut self, condition_id: impl Into<String>) -> Self {
+        self.condition_id = Some(condition_id.into());
+        self
+    }
+}
+
+/// Rewards earned by the account in one market on one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEarning {
+    /// Day the rewards were earned (`YYYY-MM-DD`)
+    pub date: String,
+    pub condition_id: String,
+    /// Token the rewards are paid in
+    pub asset_address: String,
+    pub maker_address: String,
+    /// Rewards earned, in units of the reward asset
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub earnings: Decimal,
+    /// USD rate of the reward asset
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub asset_rate: Decimal,
+}
+
+/// Paginated earnings response
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsResponse {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<UserEarning>,
+}
+
+/// Parameters for querying earnings; rewards are paid out per day
+#[derive(Debug, Clone)]
+pub struct EarningsParams {
+    pub date: NaiveDate,
+    pub signature_type: Option<u8>,
+}
+
+impl EarningsParams {
+    /// Earnings of the given day
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            date,
+            signature_type: None,
+        }
+    }
+
+    /// Signature type of the maker wallet (see [`SignatureType`](crate::SignatureType))
+    pub fn signature_type(mut self, sig_type: u8) -> Self {
+        self.signature_type = Some(sig_type);
+        self
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("date", self.date.format("%Y-%m-%d").to_string())];
+        if let Some(sig_type) = self.signature_type {
+            params.push(("signature_type", sig_type.to_string()));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_deserialize_rewards() {
+        let json = r#"{"limit":100,"count":1,"next_cursor":"LTE=","data":[{
+            "condition_id":"0xabc","rewards_max_spread":3.5,"rewards_min_size":100,
+            "rewards_config":[{"asset_address":"0xusdc","start_date":"2024-06-01",
+                "end_date":"2500-12-31","rate_per_day":25.5,"total_rewards":0}]}]}"#;
+        let response: RewardsResponse = serde_json::from_str(json).unwrap();
+        let reward = &response.data[0];
+        assert_eq!(reward.rewards_max_spread, dec!(3.5));
+        assert_eq!(reward.rewards_config[0].rate_per_day, dec!(25.5));
+        assert_eq!(reward.rewards_config[0].start_date, "2024-06-01");
+    }
+
+    #[test]
+    fn test_earnings_query_params() {
+        let params =
+            EarningsParams::new(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()).signature_type(2);
+        assert_eq!(
+            params.to_query_params(),
+            vec![
+                ("date", "2024-06-03".to_string()),
+                ("signature_type", "2".to_string())
+            ]
+        );
+    }
+}
@@ -25,6 +25,42 @@ where
     }
 }
 
+/// Deserialize an optional number from a string or number
+///
+/// `null`, a missing field (with `#[serde(default)]`) and an empty string
+/// become `None`.
+pub fn deserialize_optional_number_from_string<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + serde::Deserialize<'de>,
+    <T as FromStr>::Err: Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt<T> {
+        String(String),
+        Number(T),
+    }
+
+    match Option::<StringOrInt<T>>::deserialize(deserializer)? {
+        Some(StringOrInt::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(StringOrInt::String(s)) => s.parse::<T>().map(Some).map_err(serde::de::Error::custom),
+        Some(StringOrInt::Number(i)) => Ok(Some(i)),
+        None => Ok(None),
+    }
+}
+
+/// Deserialize a value, treating `null` as its default
+pub fn deserialize_null_default<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// Deserialize Decimal from JSON number (f64/int) or string
 pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
@@ -74,7 +110,7 @@ where
             }
 
             // Try RFC3339 first
-            if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
                 return Ok(Some(dt.with_timezone(&Utc)));
             }
 
@@ -94,7 +130,7 @@ where
             }
 
             // Try date-only format: "2022-07-27"
-            if let Ok(date) = NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
                 let dt = date
                     .and_hms_opt(0, 0, 0)
                     .ok_or_else(|| serde::de::Error::custom("invalid date"))?
@@ -1,7 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{types::ActivityType, Side};
+use crate::types::{ActivityType, MakerOrder, TradeStatus};
+use crate::Side;
 
 /// Custom deserializer for optional Side that treats empty strings as None
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
@@ -73,21 +74,29 @@ pub struct Position {
         deserialize_with = "super::serde_helpers::deserialize_decimal"
     )]
     pub cur_price: Decimal,
+    #[serde(default)]
     pub redeemable: bool,
+    #[serde(default)]
     pub mergeable: bool,
     pub title: String,
-    #[serde(rename = "eventId")]
-    pub event_id: String,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(rename = "eventId", default)]
+    pub event_id: Option<String>,
+    #[serde(rename = "eventSlug", default)]
+    pub event_slug: Option<String>,
     pub outcome: String,
     #[serde(rename = "outcomeIndex")]
     pub outcome_index: u32,
-    #[serde(rename = "oppositeOutcome")]
-    pub opposite_outcome: String,
-    #[serde(rename = "oppositeAsset")]
-    pub opposite_asset: String,
-    #[serde(rename = "endDate")]
-    pub end_date: String,
-    #[serde(rename = "negativeRisk")]
+    #[serde(rename = "oppositeOutcome", default)]
+    pub opposite_outcome: Option<String>,
+    #[serde(rename = "oppositeAsset", default)]
+    pub opposite_asset: Option<String>,
+    #[serde(rename = "endDate", default)]
+    pub end_date: Option<String>,
+    #[serde(rename = "negativeRisk", default)]
     pub negative_risk: bool,
 }
 
@@ -212,6 +221,72 @@ pub struct ClosedPosition {
     pub end_date: String,
 }
 
+/// Trade of the authenticated user from the CLOB API
+///
+/// Returned by [`TradingClient::get_trades`](crate::client::TradingClient::get_trades).
+/// Public trades of any user come from the data API as [`Trade`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClobTrade {
+    /// Trade ID
+    pub id: String,
+    /// Order of the taker in this trade
+    #[serde(default)]
+    pub taker_order_id: Option<String>,
+    /// Market (condition ID)
+    pub market: String,
+    pub asset_id: String,
+    pub side: Side,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub size: Decimal,
+    /// Fee rate in basis points
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub fee_rate_bps: Option<Decimal>,
+    pub status: TradeStatus,
+    /// Unix timestamp (seconds) of the match
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub match_time: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub last_update: Option<u64>,
+    #[serde(default)]
+    pub outcome: Option<String>,
+    /// Address of the taker's funder
+    #[serde(default)]
+    pub maker_address: Option<String>,
+    /// API key of the taker
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    /// Whether the user was the `TAKER` or a `MAKER` in this trade
+    #[serde(default)]
+    pub trader_side: Option<String>,
+    /// Maker orders matched against the taker
+    #[serde(default)]
+    pub maker_orders: Vec<MakerOrder>,
+}
+
+/// Paginated response of [`ClobTrade`]s
+#[derive(Debug, Deserialize)]
+pub struct ClobTradesResponse {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<ClobTrade>,
+}
+
 /// Parameters for querying trades
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct TradeParams {
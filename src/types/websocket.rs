@@ -74,6 +74,20 @@ pub struct PriceChange {
     /// New size at this price level (0 means remove the level)
     #[serde(with = "rust_decimal::serde::str")]
     pub size: Decimal,
+    /// Best bid of the asset after the change
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub best_bid: Option<Decimal>,
+    /// Best ask of the asset after the change
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub best_ask: Option<Decimal>,
 }
 
 /// Last trade price event
@@ -128,6 +142,7 @@ pub struct TickSizeChangeEvent {
 /// Websocket event from the authenticated user stream
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserWsEvent {
     /// Trade execution event
     Trade(TradeEvent),
@@ -174,6 +189,8 @@ pub enum TradeStatus {
     Failed,
     /// Trade has been mined on-chain
     Mined,
+    /// The transaction failed and is being retried
+    Retrying,
 }
 
 /// Maker order that was matched in a trade
@@ -55,6 +55,21 @@ where
     Ok(URL_SAFE.encode(&result.into_bytes()[..]))
 }
 
+/// Percent-encode everything but unreserved characters (RFC 3986)
+///
+/// Safe for a path segment as well as a query key or value.
+pub(crate) fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,428 @@
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+use crate::types::{BookEvent, OrderBookSummary, PriceChangeEvent, PriceLevel, WsEvent};
+use crate::Side;
+
+/// Outcome of applying an event to a [`LocalOrderBook`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookUpdate {
+    /// The book changed
+    Applied,
+    /// The event is for another asset, or doesn't affect the book
+    Ignored,
+    /// The book can't be trusted until a fresh snapshot is applied
+    ///
+    /// Returned for deltas that arrive before the first snapshot or out of
+    /// sequence. Fetch one with
+    /// [`ClobClient::get_order_book`](crate::client::ClobClient::get_order_book)
+    /// and pass it to [`LocalOrderBook::apply_summary`], or resubscribe to get
+    /// a new `book` event.
+    NeedsSnapshot,
+    /// The snapshot is older than the last applied event and was dropped
+    ///
+    /// A REST snapshot fetched before the latest delta misses that delta;
+    /// fetch another one.
+    Stale,
+}
+
+/// Order book for one token kept up to date from the market WebSocket
+///
+/// Apply every [`WsEvent`] from the stream with [`apply`](Self::apply): `book`
+/// snapshots replace the book and `price_change` deltas update single levels.
+/// [`bids`](Self::bids) and [`asks`](Self::asks) are sorted best price first,
+/// ready for [`calculate_market_price`](crate::orders::calculate_market_price).
+///
+/// Deltas are sequenced by their timestamp; one older than the last applied
+/// event marks the book as stale until the next snapshot. Snapshots older than
+/// the last applied event are dropped as [`BookUpdate::Stale`]. With
+/// [`with_hash_check`](Self::with_hash_check), deltas carrying a `hash` are
+/// also checked against the book after applying them.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{BookUpdate, LocalOrderBook, MarketWsClient};
+/// use futures_util::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let token_id = "token_id".to_string();
+/// let mut book = LocalOrderBook::new(token_id.clone());
+/// let mut stream = MarketWsClient::new().subscribe(vec![token_id]).await?;
+///
+/// while let Some(event) = stream.next().await {
+///     if book.apply(&event?) == BookUpdate::NeedsSnapshot {
+///         // Fetch the book over REST and pass it to `apply_summary`
+///     }
+///     println!("best ask: {:?}", book.asks().first());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    asset_id: String,
+    market: String,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    hash: Option<String>,
+    timestamp: Option<u64>,
+    synced: bool,
+    check_hash: bool,
+}
+
+impl LocalOrderBook {
+    /// Create an empty book for `asset_id`, waiting for its first snapshot
+    pub fn new(asset_id: impl Into<String>) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            market: String::new(),
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            hash: None,
+            timestamp: None,
+            synced: false,
+            check_hash: false,
+        }
+    }
+
+    /// Also check the `hash` of each delta against the book after applying it
+    ///
+    /// Catches a missed delta even when timestamps look in order, but relies
+    /// on [`verify_hash`](Self::verify_hash) reproducing the server's hash,
+    /// which isn't pinned against live data yet: a formatting difference would
+    /// ask for a snapshot on every delta. Off by default, leaving sequencing to
+    /// the timestamps.
+    pub fn with_hash_check(mut self, check_hash: bool) -> Self {
+        self.check_hash = check_hash;
+        self
+    }
+
+    /// Apply any market WebSocket event
+    pub fn apply(&mut self, event: &WsEvent) -> BookUpdate {
+        match event {
+            WsEvent::Book(book) => self.apply_snapshot(book),
+            WsEvent::PriceChange(change) => self.apply_price_change(change),
+            WsEvent::LastTradePrice(_) | WsEvent::TickSizeChange(_) => BookUpdate::Ignored,
+        }
+    }
+
+    /// Replace the book with a `book` snapshot
+    pub fn apply_snapshot(&mut self, event: &BookEvent) -> BookUpdate {
+        if event.asset_id != self.asset_id {
+            return BookUpdate::Ignored;
+        }
+        self.reset(
+            &event.market,
+            &event.bids,
+            &event.asks,
+            Some(event.hash.clone()),
+            event.timestamp.parse().ok(),
+        )
+    }
+
+    /// Replace the book with one fetched over REST
+    pub fn apply_summary(&mut self, summary: &OrderBookSummary) -> BookUpdate {
+        if summary.asset_id != self.asset_id {
+            return BookUpdate::Ignored;
+        }
+        self.reset(
+            &summary.market,
+            &summary.bids,
+            &summary.asks,
+            Some(summary.hash.clone()),
+            Some(summary.timestamp),
+        )
+    }
+
+    /// Apply the levels of a `price_change` event that belong to this asset
+    ///
+    /// A size of zero removes the level. With
+    /// [`with_hash_check`](Self::with_hash_check), an event carrying a `hash`
+    /// that doesn't match the book after the change means an update was
+    /// missed, and the book needs a snapshot.
+    pub fn apply_price_change(&mut self, event: &PriceChangeEvent) -> BookUpdate {
+        let mut changes = event
+            .price_changes
+            .iter()
+            .filter(|change| change.asset_id == self.asset_id)
+            .peekable();
+        if changes.peek().is_none() {
+            return BookUpdate::Ignored;
+        }
+
+        let timestamp = event.timestamp.as_deref().and_then(|ts| ts.parse().ok());
+        let out_of_sequence =
+            matches!((timestamp, self.timestamp), (Some(new), Some(last)) if new < last);
+        if !self.synced || out_of_sequence {
+            self.synced = false;
+            return BookUpdate::NeedsSnapshot;
+        }
+
+        for change in changes {
+            let levels = match change.side {
+                Side::Buy => &mut self.bids,
+                Side::Sell => &mut self.asks,
+            };
+            if change.size.is_zero() {
+                levels.remove(&change.price);
+            } else {
+                levels.insert(change.price, change.size);
+            }
+        }
+
+        if timestamp.is_some() {
+            self.timestamp = timestamp;
+        }
+        if event.hash.is_some() {
+            self.hash = event.hash.clone();
+            if self.check_hash && !self.verify_hash() {
+                self.synced = false;
+                return BookUpdate::NeedsSnapshot;
+            }
+        }
+        BookUpdate::Applied
+    }
+
+    /// Token/asset ID of this book
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// Whether a snapshot has been applied and no update was missed since
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Hash reported with the last applied event
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Whether the book matches the hash reported with the last applied event
+    ///
+    /// The hash is computed like
+    /// [`compute_book_hash`](crate::orders::compute_book_hash), with the levels
+    /// in the server's order (bids ascending, asks descending). Without a
+    /// reported hash or timestamp there is nothing to check against.
+    pub fn verify_hash(&self) -> bool {
+        let (Some(hash), Some(timestamp)) = (&self.hash, self.timestamp) else {
+            return true;
+        };
+        let mut bids = self.bids();
+        bids.reverse();
+        let mut asks = self.asks();
+        asks.reverse();
+        crate::orders::compute_book_hash(&self.market, &self.asset_id, timestamp, &bids, &asks)
+            == *hash
+    }
+
+    /// Timestamp (milliseconds) of the last applied event
+    pub fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    /// Bids, highest price first
+    pub fn bids(&self) -> Vec<PriceLevel> {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&price, &size)| PriceLevel::new(price, size))
+            .collect()
+    }
+
+    /// Asks, lowest price first
+    pub fn asks(&self) -> Vec<PriceLevel> {
+        self.asks
+            .iter()
+            .map(|(&price, &size)| PriceLevel::new(price, size))
+            .collect()
+    }
+
+    fn reset(
+        &mut self,
+        market: &str,
+        bids: &[PriceLevel],
+        asks: &[PriceLevel],
+        hash: Option<String>,
+        timestamp: Option<u64>,
+    ) -> BookUpdate {
+        if matches!((timestamp, self.timestamp), (Some(new), Some(last)) if new < last) {
+            return BookUpdate::Stale;
+        }
+
+        let levels = |side: &[PriceLevel]| {
+            side.iter()
+                .filter(|level| !level.size.is_zero())
+                .map(|level| (level.price, level.size))
+                .collect()
+        };
+        self.bids = levels(bids);
+        self.asks = levels(asks);
+        self.hash = hash;
+        self.market = market.to_string();
+        self.timestamp = timestamp;
+        self.synced = true;
+        BookUpdate::Applied
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn event(json: &str) -> WsEvent {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn snapshot(timestamp: u64) -> WsEvent {
+        event(&format!(
+            r#"{{"event_type":"book","market":"m","asset_id":"1","timestamp":"{}","hash":"h0",
+                "bids":[{{"price":"0.48","size":"10"}},{{"price":"0.49","size":"5"}}],
+                "asks":[{{"price":"0.52","size":"7"}},{{"price":"0.51","size":"3"}}]}}"#,
+            timestamp
+        ))
+    }
+
+    fn price_change(timestamp: u64, side: &str, price: &str, size: &str) -> WsEvent {
+        event(&format!(
+            r#"{{"event_type":"price_change","market":"m","timestamp":"{}",
+                "price_changes":[{{"asset_id":"1","side":"{}","price":"{}","size":"{}"}}]}}"#,
+            timestamp, side, price, size
+        ))
+    }
+
+    fn with_hash(event: WsEvent, hash: &str) -> WsEvent {
+        match event {
+            WsEvent::PriceChange(mut change) => {
+                change.hash = Some(hash.to_string());
+                WsEvent::PriceChange(change)
+            }
+            other => other,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_then_deltas() {
+        let mut book = LocalOrderBook::new("1");
+        assert_eq!(book.apply(&snapshot(100)), BookUpdate::Applied);
+        assert_eq!(book.bids()[0].price, dec!(0.49));
+        assert_eq!(book.asks()[0].price, dec!(0.51));
+
+        // New best bid, then the best ask is removed
+        assert_eq!(
+            book.apply(&price_change(101, "BUY", "0.50", "4")),
+            BookUpdate::Applied
+        );
+        assert_eq!(
+            book.apply(&price_change(102, "SELL", "0.51", "0")),
+            BookUpdate::Applied
+        );
+
+        assert_eq!(book.bids()[0].price, dec!(0.50));
+        assert_eq!(book.bids().len(), 3);
+        assert_eq!(book.asks().len(), 1);
+        assert_eq!(book.asks()[0].price, dec!(0.52));
+        assert_eq!(book.timestamp(), Some(102));
+
+        let price =
+            crate::orders::calculate_market_price(&book.asks(), dec!(7), Side::Buy).unwrap();
+        assert_eq!(price, dec!(0.52));
+    }
+
+    #[test]
+    fn test_delta_before_snapshot_needs_snapshot() {
+        let mut book = LocalOrderBook::new("1");
+        assert_eq!(
+            book.apply(&price_change(101, "BUY", "0.50", "4")),
+            BookUpdate::NeedsSnapshot
+        );
+        assert!(book.bids().is_empty());
+    }
+
+    #[test]
+    fn test_out_of_sequence_delta_needs_snapshot() {
+        let mut book = LocalOrderBook::new("1");
+        book.apply(&snapshot(100));
+        book.apply(&price_change(105, "BUY", "0.50", "4"));
+
+        assert_eq!(
+            book.apply(&price_change(103, "BUY", "0.47", "1")),
+            BookUpdate::NeedsSnapshot
+        );
+        assert!(!book.is_synced());
+        // Stays stale until a new snapshot arrives
+        assert_eq!(
+            book.apply(&price_change(106, "BUY", "0.47", "1")),
+            BookUpdate::NeedsSnapshot
+        );
+
+        assert_eq!(book.apply(&snapshot(110)), BookUpdate::Applied);
+        assert!(book.is_synced());
+        assert_eq!(book.bids()[0].price, dec!(0.49));
+    }
+
+    #[test]
+    fn test_other_assets_are_ignored() {
+        let mut book = LocalOrderBook::new("2");
+        assert_eq!(book.apply(&snapshot(100)), BookUpdate::Ignored);
+        assert_eq!(
+            book.apply(&price_change(101, "BUY", "0.50", "4")),
+            BookUpdate::Ignored
+        );
+    }
+
+    #[test]
+    fn test_stale_snapshot_is_dropped() {
+        let mut book = LocalOrderBook::new("1");
+        book.apply(&snapshot(100));
+        book.apply(&price_change(105, "BUY", "0.50", "4"));
+
+        assert_eq!(book.apply(&snapshot(103)), BookUpdate::Stale);
+        assert_eq!(book.bids()[0].price, dec!(0.50));
+        assert_eq!(book.timestamp(), Some(105));
+
+        assert_eq!(book.apply(&snapshot(105)), BookUpdate::Applied);
+        assert_eq!(book.bids()[0].price, dec!(0.49));
+    }
+
+    #[test]
+    fn test_delta_hash_is_checked_when_enabled() {
+        let mut book = LocalOrderBook::new("1").with_hash_check(true);
+        book.apply(&snapshot(100));
+
+        // Hash of the book after the change, levels in the server's order
+        let bids: Vec<PriceLevel> = vec![
+            (dec!(0.48), dec!(10)).into(),
+            (dec!(0.49), dec!(5)).into(),
+            (dec!(0.50), dec!(4)).into(),
+        ];
+        let asks: Vec<PriceLevel> =
+            vec![(dec!(0.52), dec!(7)).into(), (dec!(0.51), dec!(3)).into()];
+        let hash = crate::orders::compute_book_hash("m", "1", 101, &bids, &asks);
+
+        let change = with_hash(price_change(101, "BUY", "0.50", "4"), &hash);
+        assert_eq!(book.apply(&change), BookUpdate::Applied);
+        assert_eq!(book.hash(), Some(hash.as_str()));
+        assert!(book.verify_hash());
+
+        // A delta whose hash doesn't match means one was missed
+        let change = with_hash(price_change(102, "BUY", "0.47", "1"), &hash);
+        assert_eq!(book.apply(&change), BookUpdate::NeedsSnapshot);
+        assert!(!book.is_synced());
+    }
+
+    #[test]
+    fn test_delta_hash_is_not_checked_by_default() {
+        let mut book = LocalOrderBook::new("1");
+        book.apply(&snapshot(100));
+
+        let change = with_hash(price_change(101, "BUY", "0.50", "4"), "not the hash");
+        assert_eq!(book.apply(&change), BookUpdate::Applied);
+        assert!(book.is_synced());
+        assert_eq!(book.hash(), Some("not the hash"));
+        assert!(!book.verify_hash());
+    }
+}
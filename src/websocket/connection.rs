@@ -0,0 +1,180 @@
+use futures_util::{stream, SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::error::{Error, Result};
+
+/// Interval between keep-alive `PING` messages
+///
+/// The server drops connections that stay silent for too long, and the
+/// Polymarket docs recommend pinging every 10 seconds.
+pub(crate) const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Boxed stream of parsed WebSocket events
+pub(crate) type EventStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// Aborts the keep-alive task when the event stream is dropped
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Connect to `url`, send `initial_msg`, and stream the parsed events
+///
+/// If `ping_interval` is set, a background task sends a text `PING` at that
+/// interval for as long as the returned stream is alive.
+pub(crate) async fn connect<T>(
+    url: &str,
+    initial_msg: String,
+    ping_interval: Option<Duration>,
+) -> Result<EventStream<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let (ws_stream, _) = connect_async(url).await?;
+    let (mut write, read) = ws_stream.split();
+
+    write
+        .send(Message::Text(initial_msg))
+        .await
+        .map_err(|e| Error::WebSocket(e.to_string()))?;
+
+    let keep_alive = ping_interval.map(|interval| {
+        AbortOnDrop(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if write.send(Message::Text("PING".to_string())).await.is_err() {
+                    return;
+                }
+            }
+        }))
+    });
+
+    let stream = read
+        .map(move |msg| {
+            // Tie the keep-alive task to the lifetime of the stream
+            let _ = &keep_alive;
+            stream::iter(parse_ws_message::<T>(msg))
+        })
+        .flatten();
+
+    Ok(Box::pin(stream))
+}
+
+/// Parse a WebSocket message into zero or more events
+///
+/// The server sends either a single event object or an array of events.
+/// Keep-alive messages and control frames produce no events.
+pub(crate) fn parse_ws_message<T: DeserializeOwned>(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<T>> {
+    match msg {
+        Ok(Message::Text(text)) => {
+            // Skip empty or whitespace-only messages
+            let trimmed = text.trim();
+            if trimmed.is_empty() {
+                return Vec::new();
+            }
+
+            // Skip PING/PONG messages sent as text
+            if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
+                return Vec::new();
+            }
+
+            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(trimmed) {
+                return events
+                    .into_iter()
+                    .map(|event| serde_json::from_value(event).map_err(Error::Json))
+                    .collect();
+            }
+
+            match serde_json::from_str::<T>(trimmed) {
+                Ok(event) => vec![Ok(event)],
+                Err(e) => {
+                    // Log unexpected message format for debugging
+                    log::warn!(
+                        "Unexpected WebSocket message (first 200 chars): {}",
+                        &text.chars().take(200).collect::<String>()
+                    );
+                    vec![Err(Error::Json(e))]
+                }
+            }
+        }
+        // An abnormal close carries the reason, e.g. rejected user authentication
+        Ok(Message::Close(Some(frame))) if frame.code != CloseCode::Normal => {
+            vec![Err(Error::WebSocket(format!(
+                "Connection closed: code={}, reason={}",
+                frame.code, frame.reason
+            )))]
+        }
+        Ok(Message::Close(_)) => vec![Err(Error::ConnectionClosed)],
+        // Ping/pong frames are answered by tungstenite; raw frames aren't surfaced on read
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) | Ok(Message::Frame(_)) => Vec::new(),
+        Ok(Message::Binary(_)) => vec![Err(Error::WebSocket(
+            "Unexpected binary message".to_string(),
+        ))],
+        Err(e) => vec![Err(Error::WebSocket(e.to_string()))],
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+    #[derive(Debug, Deserialize)]
+    struct Event {
+        id: u32,
+    }
+
+    fn text(s: &str) -> std::result::Result<Message, tokio_tungstenite::tungstenite::Error> {
+        Ok(Message::Text(s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_array_yields_every_event() {
+        let events = parse_ws_message::<Event>(text(r#"[{"id": 1}, {"id": 2}]"#));
+        let ids: Vec<u32> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_parse_single_object() {
+        let events = parse_ws_message::<Event>(text(r#"{"id": 7}"#));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_ref().unwrap().id, 7);
+    }
+
+    #[test]
+    fn test_parse_skips_keep_alive() {
+        assert!(parse_ws_message::<Event>(text("PONG")).is_empty());
+        assert!(parse_ws_message::<Event>(text("  ")).is_empty());
+        assert!(parse_ws_message::<Event>(text("[]")).is_empty());
+    }
+
+    #[test]
+    fn test_parse_close() {
+        let events = parse_ws_message::<Event>(Ok(Message::Close(None)));
+        assert!(matches!(events[0], Err(Error::ConnectionClosed)));
+
+        let frame = CloseFrame {
+            code: CloseCode::Policy,
+            reason: "invalid auth".into(),
+        };
+        let events = parse_ws_message::<Event>(Ok(Message::Close(Some(frame))));
+        match &events[0] {
+            Err(Error::WebSocket(msg)) => assert!(msg.contains("invalid auth"), "{}", msg),
+            other => panic!("unexpected: {:?}", other),
+        }
+    }
+}
@@ -1,12 +1,20 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::{stream, Stream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
 
-use crate::error::{Error, Result};
+use super::connection::{self, DEFAULT_PING_INTERVAL};
+use super::{ConnectFuture, ReconnectConfig, ReconnectingStream, WsReconnectingStream};
+use crate::error::Result;
+use crate::orders::mid_price;
 use crate::types::{MarketSubscription, WsEvent};
 
+/// Stream of `(asset_id, price)` updates, see [`MarketWsClient::price_updates`]
+pub type PriceUpdateStream = Pin<Box<dyn Stream<Item = Result<(String, Decimal)>> + Send>>;
+
 /// Handle for querying WebSocket subscription state
 ///
 /// This handle provides read-only access to the current token IDs
@@ -36,87 +44,16 @@ impl SubscriptionHandle {
 /// # Connection Management
 ///
 /// The Polymarket WebSocket server will disconnect idle connections after 1-2 minutes.
-/// The Python client uses `ping_interval=5` to send keep-alive pings every 5 seconds.
+/// While a stream is alive the client sends a keep-alive `PING` every 10 seconds
+/// (see [`with_ping_interval`](Self::with_ping_interval)).
 ///
-/// For Rust, the recommended approach is to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
-/// which automatically handles connection resets and reconnects with exponential backoff.
-/// This is more robust than manual ping/pong management.
+/// Connections can still drop, so for long-running consumers use
+/// [`subscribe_with_reconnect`](Self::subscribe_with_reconnect), which reconnects
+/// with exponential backoff and resubscribes to the same tokens.
 #[derive(Debug, Clone)]
 pub struct MarketWsClient {
     ws_url: String,
-}
-
-/// Parse a WebSocket message into a WsEvent
-///
-/// This is a helper function that handles the parsing logic shared by both
-/// subscribe() and subscribe_with_handle() methods.
-fn parse_ws_message(
-    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
-    match msg {
-        Ok(Message::Text(text)) => {
-            // Skip empty or whitespace-only messages
-            let trimmed = text.trim();
-            if trimmed.is_empty() {
-                return None;
-            }
-
-            // Skip PING/PONG messages sent as text (some servers do this)
-            if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
-            }
-
-            // The server can send either a single object or an array
-            // Try to parse as array first
-            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
-            }
-
-            // Try parsing as single object
-            match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
-                Err(e) => {
-                    // Log unexpected message format for debugging
-                    log::warn!(
-                        "Unexpected WebSocket message (first 200 chars): {}",
-                        &text.chars().take(200).collect::<String>()
-                    );
-                    Some(Err(Error::Json(e)))
-                }
-            }
-        }
-        Ok(Message::Close(_)) => {
-            // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
-        }
-        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-            // Ignore ping/pong frames (handled automatically)
-            None
-        }
-        Ok(Message::Binary(_)) => {
-            // Unexpected binary message
-            Some(Err(Error::WebSocket(
-                "Unexpected binary message".to_string(),
-            )))
-        }
-        Ok(Message::Frame(_)) => {
-            // Raw frame (shouldn't happen)
-            None
-        }
-        Err(e) => {
-            // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
-        }
-    }
+    ping_interval: Option<Duration>,
 }
 
 impl MarketWsClient {
@@ -125,18 +62,36 @@ impl MarketWsClient {
 
     /// Create a new market WebSocket client with the default endpoint
     pub fn new() -> Self {
-        Self {
-            ws_url: Self::DEFAULT_WS_URL.to_string(),
-        }
+        Self::with_url(Self::DEFAULT_WS_URL)
     }
 
     /// Create a new market WebSocket client with a custom endpoint
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            ping_interval: Some(DEFAULT_PING_INTERVAL),
         }
     }
 
+    /// Set the keep-alive ping interval (`None` disables pings)
+    pub fn with_ping_interval(mut self, ping_interval: Option<Duration>) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    /// Connect and send the subscription for `token_ids`
+    async fn connect(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        let subscription = MarketSubscription {
+            assets_ids: token_ids,
+        };
+        let subscription_msg = serde_json::to_string(&subscription)?;
+
+        connection::connect(&self.ws_url, subscription_msg, self.ping_interval).await
+    }
+
     /// Subscribe to market updates with a handle to query subscription state
     ///
     /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
@@ -174,27 +129,7 @@ impl MarketWsClient {
         Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
         SubscriptionHandle,
     )> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
-
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids.clone(),
-        };
-
-        let subscription_msg = serde_json::to_string(&subscription)?;
-
-        // Send initial subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
-
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        let stream = self.connect(token_ids.clone()).await?;
 
         // Create shared state for current tokens
         let current_tokens = Arc::new(RwLock::new(token_ids));
@@ -202,10 +137,7 @@ impl MarketWsClient {
         // Create subscription handle
         let handle = SubscriptionHandle { current_tokens };
 
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
-
-        Ok((Box::pin(stream), handle))
+        Ok((stream, handle))
     }
 
     /// Subscribe to market updates for the specified token IDs
@@ -237,32 +169,139 @@ impl MarketWsClient {
         &self,
         token_ids: Vec<String>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
-
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids,
-        };
-
-        let subscription_msg = serde_json::to_string(&subscription)?;
+        self.connect(token_ids).await
+    }
 
-        // Send subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
+    /// Subscribe to a live price feed for `token_ids`
+    ///
+    /// A lighter alternative to maintaining a
+    /// [`LocalOrderBook`](super::LocalOrderBook) per token: the stream only
+    /// yields `(asset_id, price)` when the price of an asset changes, see
+    /// [`price_updates`] for how prices are derived.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::websocket::MarketWsClient;
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = MarketWsClient::new();
+    /// let mut prices = client
+    ///     .price_updates(vec!["token_a".to_string(), "token_b".to_string()])
+    ///     .await?;
+    ///
+    /// while let Some(update) = prices.next().await {
+    ///     let (asset_id, price) = update?;
+    ///     println!("{}: {}", asset_id, price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn price_updates(&self, token_ids: Vec<String>) -> Result<PriceUpdateStream> {
+        let events = self.connect(token_ids).await?;
+        Ok(Box::pin(price_updates(events)))
+    }
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+    /// Subscribe to market updates and reconnect automatically
+    ///
+    /// Each reconnection sends the subscription for `token_ids` again, so the
+    /// stream resumes with a fresh [`WsEvent::Book`] snapshot. Errors from a
+    /// dropped connection are yielded before reconnecting.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::websocket::{MarketWsClient, ReconnectConfig};
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = MarketWsClient::new();
+    /// let mut stream =
+    ///     client.subscribe_with_reconnect(vec!["token_id".to_string()], ReconnectConfig::default());
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     match event {
+    ///         Ok(event) => println!("Event: {:?}", event),
+    ///         Err(e) => eprintln!("Connection error: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_with_reconnect(
+        &self,
+        token_ids: Vec<String>,
+        config: ReconnectConfig,
+    ) -> WsReconnectingStream<WsEvent> {
+        let client = self.clone();
+        ReconnectingStream::new(
+            config,
+            Box::new(move || -> ConnectFuture<WsEvent> {
+                let client = client.clone();
+                let token_ids = token_ids.clone();
+                Box::pin(async move { client.connect(token_ids).await })
+            }),
+        )
+    }
+}
 
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+/// Reduce market events to `(asset_id, price)` pairs
+///
+/// The price of an asset is:
+/// - the midpoint of a `book` snapshot,
+/// - the midpoint of the best bid and ask reported with a `price_change`,
+/// - the price of a `last_trade_price` event.
+///
+/// A price equal to the last one yielded for the same asset is skipped, as
+/// are events without a price (a one-sided book, a change without best
+/// prices, tick size changes). Errors are passed through.
+///
+/// [`MarketWsClient::price_updates`] applies this to a new subscription; use
+/// it directly on a [`subscribe_with_reconnect`](MarketWsClient::subscribe_with_reconnect)
+/// stream for a feed that survives disconnects.
+pub fn price_updates<S>(events: S) -> impl Stream<Item = Result<(String, Decimal)>>
+where
+    S: Stream<Item = Result<WsEvent>>,
+{
+    events
+        .scan(
+            HashMap::new(),
+            |last: &mut HashMap<String, Decimal>, event| {
+                let updates: Vec<Result<(String, Decimal)>> = match event {
+                    Ok(event) => event_prices(event)
+                        .into_iter()
+                        .filter(|(asset_id, price)| {
+                            last.insert(asset_id.clone(), *price) != Some(*price)
+                        })
+                        .map(Ok)
+                        .collect(),
+                    Err(e) => vec![Err(e)],
+                };
+                std::future::ready(Some(stream::iter(updates)))
+            },
+        )
+        .flatten()
+}
 
-        Ok(Box::pin(stream))
+/// Prices carried by one event, per asset
+fn event_prices(event: WsEvent) -> Vec<(String, Decimal)> {
+    match event {
+        WsEvent::Book(book) => mid_price(&book.bids, &book.asks)
+            .map(|mid| (book.asset_id, mid))
+            .into_iter()
+            .collect(),
+        WsEvent::PriceChange(change) => change
+            .price_changes
+            .into_iter()
+            .filter_map(|change| {
+                let mid = (change.best_bid? + change.best_ask?) / Decimal::TWO;
+                Some((change.asset_id, mid))
+            })
+            .collect(),
+        WsEvent::LastTradePrice(trade) => vec![(trade.asset_id, trade.price)],
+        WsEvent::TickSizeChange(_) => Vec::new(),
     }
 }
 
@@ -288,4 +327,93 @@ mod tests {
         let client = MarketWsClient::with_url(custom_url);
         assert_eq!(client.ws_url, custom_url);
     }
+
+    #[tokio::test]
+    async fn test_price_updates_dedupes_unchanged_prices() {
+        let events = [
+            r#"{"event_type":"book","market":"m","asset_id":"1","timestamp":"0","hash":"h",
+                "bids":[{"price":"0.48","size":"10"}],"asks":[{"price":"0.52","size":"10"}]}"#,
+            // Same midpoint as the snapshot
+            r#"{"event_type":"price_change","market":"m","price_changes":[
+                {"asset_id":"1","side":"BUY","price":"0.47","size":"5","best_bid":"0.48","best_ask":"0.52"},
+                {"asset_id":"2","side":"BUY","price":"0.30","size":"5","best_bid":"0.30","best_ask":"0.34"}]}"#,
+            r#"{"event_type":"last_trade_price","market":"m","asset_id":"1","price":"0.52",
+                "size":"10","fee_rate_bps":"0","side":"BUY","timestamp":"1","transaction_hash":"0x"}"#,
+            r#"{"event_type":"tick_size_change","market":"m","asset_id":"1",
+                "old_tick_size":"0.01","new_tick_size":"0.001","timestamp":"2"}"#,
+            r#"{"event_type":"last_trade_price","market":"m","asset_id":"1","price":"0.52",
+                "size":"3","fee_rate_bps":"0","side":"SELL","timestamp":"3","transaction_hash":"0x"}"#,
+        ];
+        let events = events
+            .iter()
+            .map(|json| Ok(serde_json::from_str::<WsEvent>(json).unwrap()))
+            .chain([Err(crate::Error::ConnectionClosed)]);
+
+        let updates: Vec<_> = price_updates(stream::iter(events)).collect().await;
+        assert_eq!(updates.len(), 4);
+        let prices: Vec<_> = updates[..3]
+            .iter()
+            .map(|update| update.as_ref().unwrap().clone())
+            .collect();
+        assert_eq!(
+            prices,
+            vec![
+                ("1".to_string(), Decimal::new(50, 2)),
+                ("2".to_string(), Decimal::new(32, 2)),
+                ("1".to_string(), Decimal::new(52, 2)),
+            ]
+        );
+        assert!(matches!(updates[3], Err(crate::Error::ConnectionClosed)));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_with_reconnect_resubscribes() {
+        use crate::websocket::ConnectionState;
+        use futures_util::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (sub_tx, mut sub_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        // Each connection receives one book snapshot and is then closed
+        tokio::spawn(async move {
+            for hash in ["h1", "h2"] {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                let sub = ws.next().await.unwrap().unwrap().into_text().unwrap();
+                sub_tx.send(sub).unwrap();
+
+                let book = format!(
+                    r#"[{{"event_type":"book","market":"m","asset_id":"1","timestamp":"0","hash":"{}","bids":[],"asks":[]}}]"#,
+                    hash
+                );
+                ws.send(Message::Text(book)).await.unwrap();
+                ws.close(None).await.unwrap();
+            }
+        });
+
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ..ReconnectConfig::default()
+        };
+        let mut stream = MarketWsClient::with_url(url)
+            .with_ping_interval(None)
+            .subscribe_with_reconnect(vec!["1".to_string()], config);
+
+        assert_eq!(stream.state(), ConnectionState::Connecting);
+        for expected in ["h1", "h2"] {
+            match stream.next().await.unwrap().unwrap() {
+                WsEvent::Book(book) => assert_eq!(book.hash, expected),
+                other => panic!("unexpected event: {:?}", other),
+            }
+            assert_eq!(stream.state(), ConnectionState::Connected);
+        }
+
+        for _ in 0..2 {
+            let sub = sub_rx.recv().await.unwrap();
+            assert!(sub.contains(r#""assets_ids":["1"]"#), "{}", sub);
+        }
+    }
 }
@@ -7,15 +7,29 @@
 //! # Connection Management
 //!
 //! The Polymarket WebSocket server may disconnect idle connections after 1-2 minutes.
-//! For production use, it's recommended to use [`ReconnectingStream`] to automatically
-//! handle disconnections and reconnect with exponential backoff.
+//! Clients send a keep-alive `PING` every 10 seconds while a stream is alive, but
+//! for production use it's recommended to use [`ReconnectingStream`] (or
+//! [`MarketWsClient::subscribe_with_reconnect`]) to automatically handle
+//! disconnections and reconnect with jittered exponential backoff. Its
+//! [`ConnectionState`] can be read or watched to react to disconnects.
+//!
+//! # Cancellation
+//!
+//! Dropping a stream closes its connection and aborts the keep-alive task, so
+//! a loop over a stream can be stopped from `tokio::select!` on a shutdown
+//! signal without leaving tasks behind.
 
+mod book;
+mod connection;
 mod market;
 mod stream;
 mod user;
 
-pub use market::{MarketWsClient, SubscriptionHandle};
-pub use stream::{ReconnectConfig, ReconnectingStream};
+pub use book::{BookUpdate, LocalOrderBook};
+pub use market::{price_updates, MarketWsClient, PriceUpdateStream, SubscriptionHandle};
+pub use stream::{
+    ConnectFuture, ConnectionState, ReconnectConfig, ReconnectingStream, WsReconnectingStream,
+};
 pub use user::UserWsClient;
 
 // Re-export commonly used types for convenience
@@ -1,8 +1,10 @@
 use futures_util::Stream;
+use rand::Rng;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::Duration;
+use tokio::sync::watch;
 use tokio::time::sleep;
 
 use crate::error::{Error, Result};
@@ -16,8 +18,14 @@ pub struct ReconnectConfig {
     pub max_delay: Duration,
     /// Multiplier for exponential backoff
     pub multiplier: f64,
-    /// Maximum number of reconnection attempts (None = infinite)
+    /// Maximum number of reconnection attempts per disconnection, not counting
+    /// the first connection (None = infinite)
     pub max_attempts: Option<u32>,
+    /// Random extra delay added to each backoff, as a fraction of it (0.0 = none)
+    ///
+    /// Spreads out reconnects from many clients dropped at the same time.
+    /// The jittered delay is still capped at `max_delay`.
+    pub jitter: f64,
 }
 
 impl Default for ReconnectConfig {
@@ -27,13 +35,49 @@ impl Default for ReconnectConfig {
             max_delay: Duration::from_secs(60),
             multiplier: 2.0,
             max_attempts: None,
+            jitter: 0.2,
         }
     }
 }
 
+/// Connection state of a [`ReconnectingStream`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Opening the first connection
+    Connecting,
+    /// Connected and streaming events
+    Connected,
+    /// Connection lost; waiting for or making reconnection attempt `attempt`
+    Reconnecting { attempt: u32 },
+    /// The stream has ended, e.g. after exhausting `max_attempts`
+    Closed,
+}
+
+/// Boxed future opening a connection for a [`WsReconnectingStream`]
+pub type ConnectFuture<T> =
+    Pin<Box<dyn Future<Output = Result<Pin<Box<dyn Stream<Item = Result<T>> + Send>>>> + Send>>;
+
+/// Reconnecting stream returned by the clients' `subscribe_with_reconnect`
+pub type WsReconnectingStream<T> = ReconnectingStream<
+    T,
+    Pin<Box<dyn Stream<Item = Result<T>> + Send>>,
+    Box<dyn Fn() -> ConnectFuture<T> + Send + Sync>,
+    ConnectFuture<T>,
+>;
+
+/// Add up to `jitter * delay` of random delay, capped at `max_delay`
+fn with_jitter(delay: Duration, jitter: f64, max_delay: Duration) -> Duration {
+    if jitter <= 0.0 {
+        return delay;
+    }
+    let extra = delay.as_secs_f64() * jitter * rand::thread_rng().gen::<f64>();
+    std::cmp::min(delay + Duration::from_secs_f64(extra), max_delay)
+}
+
 /// Exponential backoff calculator
 #[derive(Debug, Clone)]
 struct ExponentialBackoff {
+    initial_delay: Duration,
     current_delay: Duration,
     max_delay: Duration,
     multiplier: f64,
@@ -42,6 +86,7 @@ struct ExponentialBackoff {
 impl ExponentialBackoff {
     fn new(initial_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
         Self {
+            initial_delay,
             current_delay: initial_delay,
             max_delay,
             multiplier,
@@ -60,7 +105,7 @@ impl ExponentialBackoff {
 
     /// Reset the backoff to initial delay
     fn reset(&mut self) {
-        self.current_delay = Duration::from_secs(1);
+        self.current_delay = self.initial_delay;
     }
 }
 
@@ -68,12 +113,12 @@ impl ExponentialBackoff {
 enum StreamState<S, Fut> {
     /// Currently connected and streaming
     Connected(S),
-    /// Connection failed, waiting to reconnect
+    /// Connection failed, waiting to reconnect after `attempts` failed reconnects
     Reconnecting {
         attempts: u32,
         delay: Duration,
     },
-    /// Reconnection in progress
+    /// Connection in progress; `attempts` counts this one, 0 for the first connection
     Connecting {
         attempts: u32,
         future: Option<Pin<Box<Fut>>>,
@@ -133,6 +178,8 @@ where
     backoff: ExponentialBackoff,
     /// Sleep future for reconnection delay
     sleep_future: Option<Pin<Box<tokio::time::Sleep>>>,
+    /// Publishes the connection state
+    state_tx: watch::Sender<ConnectionState>,
 }
 
 impl<T, S, F, Fut> ReconnectingStream<T, S, F, Fut>
@@ -163,26 +210,56 @@ where
             config,
             backoff,
             sleep_future: None,
+            state_tx: watch::Sender::new(ConnectionState::Connecting),
         }
     }
 
+    /// Current connection state
+    pub fn state(&self) -> ConnectionState {
+        *self.state_tx.borrow()
+    }
+
+    /// Receiver notified on every connection state change
+    ///
+    /// Useful to react to disconnects from another task while the stream is
+    /// being consumed.
+    pub fn watch_state(&self) -> watch::Receiver<ConnectionState> {
+        self.state_tx.subscribe()
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        self.state_tx.send_replace(state);
+    }
+
     /// Handle a disconnection and prepare for reconnection
-    fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
+    ///
+    /// `attempts` is the number of reconnection attempts made since the
+    /// connection was last up, 0 for a fresh disconnect. Returns the error to
+    /// yield if the maximum number of attempts was reached.
+    fn handle_disconnection(&mut self, attempts: u32, last_error: String) -> Option<Error> {
         // Check if we've exceeded max attempts
         if let Some(max) = self.config.max_attempts {
             if attempts >= max {
                 self.state = StreamState::Terminated;
-                return Poll::Ready(Some(Err(Error::ReconnectFailed {
+                self.set_state(ConnectionState::Closed);
+                return Some(Error::ReconnectFailed {
                     attempts,
-                    last_error: "Maximum reconnection attempts reached".to_string(),
-                })));
+                    last_error,
+                });
             }
         }
 
-        let delay = self.backoff.next_delay();
+        let delay = with_jitter(
+            self.backoff.next_delay(),
+            self.config.jitter,
+            self.config.max_delay,
+        );
         self.state = StreamState::Reconnecting { attempts, delay };
+        self.set_state(ConnectionState::Reconnecting {
+            attempt: attempts + 1,
+        });
         self.sleep_future = Some(Box::pin(sleep(delay)));
-        Poll::Pending
+        None
     }
 }
 
@@ -206,16 +283,28 @@ where
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
                             // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            let closed = Error::ConnectionClosed.to_string();
+                            if let Some(err) = self.handle_disconnection(0, closed) {
+                                return Poll::Ready(Some(Err(err)));
+                            }
+                            continue;
+                        }
+                        Poll::Ready(Some(Err(e @ Error::Json(_)))) => {
+                            // A malformed message doesn't mean the connection is gone
+                            return Poll::Ready(Some(Err(e)));
                         }
                         Poll::Ready(Some(Err(e))) => {
                             // Other error, pass through and prepare to reconnect
-                            let _ = self.handle_disconnection(1);
+                            let _ = self.handle_disconnection(0, e.to_string());
                             return Poll::Ready(Some(Err(e)));
                         }
                         Poll::Ready(None) => {
                             // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            let ended = "Stream ended".to_string();
+                            if let Some(err) = self.handle_disconnection(0, ended) {
+                                return Poll::Ready(Some(Err(err)));
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             return Poll::Pending;
@@ -230,7 +319,7 @@ where
                             Poll::Ready(()) => {
                                 // Delay complete, start connecting
                                 self.state = StreamState::Connecting {
-                                    attempts,
+                                    attempts: attempts + 1,
                                     future: None,
                                 };
                                 continue;
@@ -262,14 +351,18 @@ where
                     match boxed_fut.as_mut().poll(cx) {
                         Poll::Ready(Ok(stream)) => {
                             self.state = StreamState::Connected(stream);
+                            self.set_state(ConnectionState::Connected);
                             self.backoff.reset();
                             continue;
                         }
-                        Poll::Ready(Err(_e)) => {
+                        Poll::Ready(Err(e)) => {
                             // Connection failed, prepare to reconnect
-                            // Increment attempts (or start at 1 if this is the first attempt)
-                            let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            if let Some(err) =
+                                self.handle_disconnection(current_attempts, e.to_string())
+                            {
+                                return Poll::Ready(Some(Err(err)));
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             // Store the future for next poll
@@ -282,6 +375,7 @@ where
                     }
                 }
                 StreamState::Terminated => {
+                    self.set_state(ConnectionState::Closed);
                     return Poll::Ready(None);
                 }
             }
@@ -337,4 +431,65 @@ mod tests {
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_jitter_bounds() {
+        let delay = Duration::from_secs(1);
+        assert_eq!(with_jitter(delay, 0.0, Duration::from_secs(60)), delay);
+
+        for _ in 0..100 {
+            let jittered = with_jitter(delay, 0.5, Duration::from_secs(60));
+            assert!(jittered >= delay && jittered <= Duration::from_millis(1500));
+        }
+        assert_eq!(
+            with_jitter(delay, 0.5, Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_state_transitions_and_max_attempts() {
+        use futures_util::{stream, StreamExt};
+
+        type Items = stream::Iter<std::vec::IntoIter<Result<u32>>>;
+
+        for max_attempts in [1, 2] {
+            let connects = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+            let counter = connects.clone();
+            let config = ReconnectConfig {
+                initial_delay: Duration::from_millis(1),
+                max_attempts: Some(max_attempts),
+                ..ReconnectConfig::default()
+            };
+
+            // The first connection yields one item and ends, every later one fails
+            let mut stream = ReconnectingStream::new(config, move || {
+                let n = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async move {
+                    if n == 0 {
+                        Ok::<Items, Error>(stream::iter(vec![Ok(1)]))
+                    } else {
+                        Err(Error::ConnectionClosed)
+                    }
+                }
+            });
+            let mut states = stream.watch_state();
+            assert_eq!(stream.state(), ConnectionState::Connecting);
+
+            assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+            assert_eq!(*states.borrow_and_update(), ConnectionState::Connected);
+
+            let end = stream.next().await.unwrap();
+            assert!(
+                matches!(end, Err(Error::ReconnectFailed { attempts, .. }) if attempts == max_attempts)
+            );
+            assert_eq!(stream.state(), ConnectionState::Closed);
+            assert!(stream.next().await.is_none());
+            // The first connection plus `max_attempts` reconnects
+            assert_eq!(
+                connects.load(std::sync::atomic::Ordering::SeqCst),
+                max_attempts + 1
+            );
+        }
+    }
 }
@@ -1,8 +1,10 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::Stream;
 use std::pin::Pin;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
 
-use crate::error::{Error, Result};
+use super::connection::{self, DEFAULT_PING_INTERVAL};
+use super::{ConnectFuture, ReconnectConfig, ReconnectingStream, WsReconnectingStream};
+use crate::error::Result;
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 
 /// WebSocket client for streaming authenticated user events
@@ -13,8 +15,22 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 /// # Connection Management
 ///
 /// The Polymarket WebSocket server will disconnect idle connections after 1-2 minutes.
-/// For production use, it's recommended to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
-/// to automatically handle disconnections and reconnect with exponential backoff.
+/// While a stream is alive the client sends a keep-alive `PING` every 10 seconds
+/// (see [`with_ping_interval`](Self::with_ping_interval)). For production use, it's
+/// recommended to use [`subscribe_with_reconnect`](Self::subscribe_with_reconnect) or
+/// [`ReconnectingStream`](crate::websocket::ReconnectingStream) to automatically handle
+/// disconnections and re-authenticate with exponential backoff.
+///
+/// # Authentication
+///
+/// Right after connecting, the client sends the L2 API credentials as
+/// [`UserAuthentication`]:
+///
+/// ```json
+/// {"type": "user", "auth": {"apiKey": "...", "secret": "...", "passphrase": "..."}}
+/// ```
+///
+/// The server closes the connection if the credentials are rejected.
 ///
 /// # Example with Auto-Reconnect
 ///
@@ -39,6 +55,7 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 ///         max_delay: Duration::from_secs(30),
 ///         multiplier: 2.0,
 ///         max_attempts: None,
+///         jitter: 0.2,
 ///     };
 ///
 ///     let creds_clone = creds.clone();
@@ -60,6 +77,7 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 #[derive(Debug, Clone)]
 pub struct UserWsClient {
     ws_url: String,
+    ping_interval: Option<Duration>,
 }
 
 impl UserWsClient {
@@ -68,18 +86,23 @@ impl UserWsClient {
 
     /// Create a new user WebSocket client with the default endpoint
     pub fn new() -> Self {
-        Self {
-            ws_url: Self::DEFAULT_WS_URL.to_string(),
-        }
+        Self::with_url(Self::DEFAULT_WS_URL)
     }
 
     /// Create a new user WebSocket client with a custom endpoint
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            ping_interval: Some(DEFAULT_PING_INTERVAL),
         }
     }
 
+    /// Set the keep-alive ping interval (`None` disables pings)
+    pub fn with_ping_interval(mut self, ping_interval: Option<Duration>) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
     /// Subscribe to user events with API credentials
     ///
     /// Returns a stream of [`UserWsEvent`] items. The stream will yield events as they
@@ -166,80 +189,52 @@ impl UserWsClient {
         api_secret: String,
         api_passphrase: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (mut write, read) = ws_stream.split();
-
-        // Create authentication message
         let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
-
         let auth_msg = serde_json::to_string(&auth)?;
 
-        // Send authentication message
-        write
-            .send(Message::Text(auth_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
-
-        // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
-
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+        connection::connect(&self.ws_url, auth_msg, self.ping_interval).await
+    }
 
-        Ok(Box::pin(stream))
+    /// Subscribe to user events and reconnect automatically
+    ///
+    /// Each reconnection authenticates again with `creds`. Errors from a dropped
+    /// connection, including a close caused by rejected credentials, are yielded
+    /// before reconnecting.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::websocket::{ReconnectConfig, UserWsClient};
+    /// use polymarket_rs::types::ApiCreds;
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let creds = ApiCreds::new("key".into(), "secret".into(), "passphrase".into());
+    /// let mut stream = UserWsClient::new().subscribe_with_reconnect(creds, ReconnectConfig::default());
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     match event {
+    ///         Ok(event) => println!("Event: {:?}", event),
+    ///         Err(e) => eprintln!("Connection error: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_with_reconnect(
+        &self,
+        creds: ApiCreds,
+        config: ReconnectConfig,
+    ) -> WsReconnectingStream<UserWsEvent> {
+        let client = self.clone();
+        ReconnectingStream::new(
+            config,
+            Box::new(move || -> ConnectFuture<UserWsEvent> {
+                let client = client.clone();
+                let creds = creds.clone();
+                Box::pin(async move { client.subscribe_with_creds(&creds).await })
+            }),
+        )
     }
 }
 
@@ -258,4 +253,49 @@ mod tests {
         let client = UserWsClient::new();
         assert_eq!(client.ws_url, UserWsClient::DEFAULT_WS_URL);
     }
+
+    #[tokio::test]
+    async fn test_sends_auth_and_reports_rejection() {
+        use futures_util::StreamExt;
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+        use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let auth = ws.next().await.unwrap().unwrap().into_text().unwrap();
+            ws.close(Some(CloseFrame {
+                code: CloseCode::Policy,
+                reason: "invalid credentials".into(),
+            }))
+            .await
+            .unwrap();
+            serde_json::from_str::<serde_json::Value>(&auth).unwrap()
+        });
+
+        let creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+        let mut stream = UserWsClient::with_url(url)
+            .with_ping_interval(None)
+            .subscribe_with_creds(&creds)
+            .await
+            .unwrap();
+
+        match stream.next().await.unwrap() {
+            Err(crate::Error::WebSocket(msg)) => assert!(msg.contains("invalid credentials")),
+            other => panic!("unexpected: {:?}", other),
+        }
+
+        let auth = server.await.unwrap();
+        assert_eq!(
+            auth,
+            serde_json::json!({
+                "type": "user",
+                "auth": {"apiKey": "key", "secret": "secret", "passphrase": "pass"}
+            })
+        );
+    }
 }